@@ -1,6 +1,10 @@
 //! Product features including surrender charges, payout factors, and rider terms
 
 use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use super::pwd::{Currency, RoundingMode};
 
 /// Surrender charge schedule by policy year
 #[derive(Debug, Clone)]
@@ -56,103 +60,146 @@ impl SurrenderChargeSchedule {
     }
 }
 
-/// GLWB payout factors by attained age
+/// GLWB payout factors by attained age.
+///
+/// Bands are stored as a `(min_age, max_age, rate)` vector kept sorted by `min_age` and validated
+/// at construction to be contiguous (no overlaps, no gaps), so lookup is an unambiguous O(log n)
+/// binary search rather than an order-dependent scan of a `HashMap`.
 #[derive(Debug, Clone)]
 pub struct PayoutFactors {
-    /// Single life payout factors by age band
-    single_life: HashMap<(u8, u8), f64>,
-    /// Joint life payout factors by age band (optional)
-    joint_life: Option<HashMap<(u8, u8), f64>>,
+    /// Single life payout bands, sorted by `min_age`, contiguous and non-overlapping.
+    single_life: Vec<(u8, u8, f64)>,
+    /// Joint life payout bands (optional), same invariants as `single_life`.
+    joint_life: Option<Vec<(u8, u8, f64)>>,
+    /// When true, `get_single_life`/`get_joint_life` linearly interpolate the rate between the
+    /// anchor ages (each band's `min_age`) of adjacent bands instead of returning the flat band
+    /// rate, so ages issued between the published grid ages get a blended factor.
+    interpolate: bool,
 }
 
 impl PayoutFactors {
-    /// Create from loaded CSV data (HashMap<age, factor>)
-    pub fn from_loaded(factors: &std::collections::HashMap<u8, f64>) -> Self {
-        // Convert direct age->factor mapping to age bands
-        // For now, store as single-year bands
-        let mut single_life = HashMap::new();
-        for (&age, &factor) in factors {
-            single_life.insert((age, age), factor);
-        }
-        Self {
-            single_life,
-            joint_life: None,
-        }
+    /// Create from loaded CSV data (`HashMap<age, factor>`), as single-year bands.
+    ///
+    /// Panics if the loaded ages don't form a contiguous, non-overlapping set of bands — the
+    /// payout grid is a system boundary (external file), so a malformed one should fail fast
+    /// rather than silently mis-price withdrawals.
+    pub fn from_loaded(factors: &HashMap<u8, f64>) -> Self {
+        let bands: Vec<(u8, u8, f64)> = factors.iter().map(|(&age, &factor)| (age, age, factor)).collect();
+        Self::from_bands(&bands, None, false).expect("invalid payout factor bands in loaded assumptions")
     }
 
     /// Create default payout factors from Product features sheet
     /// Uses per-age factors from Excel (not banded)
     pub fn default() -> Self {
-        let mut single_life = HashMap::new();
-
-        // Per-age payout factors from Excel Product features sheet
-        // Ages 50-55 use band rate
-        single_life.insert((50, 55), 0.046);
-        // Ages 56+ use per-year rates
-        single_life.insert((56, 56), 0.0475);
-        single_life.insert((57, 57), 0.049);
-        single_life.insert((58, 58), 0.0505);
-        single_life.insert((59, 59), 0.052);
-        single_life.insert((60, 60), 0.0535);
-        single_life.insert((61, 61), 0.055);
-        single_life.insert((62, 62), 0.0565);
-        single_life.insert((63, 63), 0.058);
-        single_life.insert((64, 64), 0.0595);
-        single_life.insert((65, 65), 0.0605);
-        single_life.insert((66, 66), 0.061);
-        single_life.insert((67, 67), 0.062);
-        single_life.insert((68, 68), 0.0625);
-        single_life.insert((69, 69), 0.0635);
-        single_life.insert((70, 70), 0.0645);
-        single_life.insert((71, 71), 0.0655);
-        single_life.insert((72, 72), 0.0665);
-        single_life.insert((73, 73), 0.0675);
-        single_life.insert((74, 74), 0.069);
-        single_life.insert((75, 75), 0.0705);
-        single_life.insert((76, 76), 0.0725);
-        single_life.insert((77, 77), 0.0745);
-        single_life.insert((78, 78), 0.0765);
-        single_life.insert((79, 79), 0.0785);
-        single_life.insert((80, 80), 0.0795);
-        single_life.insert((81, 81), 0.0805);
-        single_life.insert((82, 82), 0.0815);
-        single_life.insert((83, 83), 0.0825);
-        single_life.insert((84, 84), 0.0835);
-        single_life.insert((85, 85), 0.0845);
-        single_life.insert((86, 86), 0.0855);
-        single_life.insert((87, 87), 0.0865);
-        single_life.insert((88, 88), 0.0875);
-        single_life.insert((89, 89), 0.0885);
-        single_life.insert((90, 120), 0.0895);  // 90+ use max rate
+        let single_life: Vec<(u8, u8, f64)> = vec![
+            // Ages 50-55 use band rate
+            (50, 55, 0.046),
+            // Ages 56+ use per-year rates
+            (56, 56, 0.0475),
+            (57, 57, 0.049),
+            (58, 58, 0.0505),
+            (59, 59, 0.052),
+            (60, 60, 0.0535),
+            (61, 61, 0.055),
+            (62, 62, 0.0565),
+            (63, 63, 0.058),
+            (64, 64, 0.0595),
+            (65, 65, 0.0605),
+            (66, 66, 0.061),
+            (67, 67, 0.062),
+            (68, 68, 0.0625),
+            (69, 69, 0.0635),
+            (70, 70, 0.0645),
+            (71, 71, 0.0655),
+            (72, 72, 0.0665),
+            (73, 73, 0.0675),
+            (74, 74, 0.069),
+            (75, 75, 0.0705),
+            (76, 76, 0.0725),
+            (77, 77, 0.0745),
+            (78, 78, 0.0765),
+            (79, 79, 0.0785),
+            (80, 80, 0.0795),
+            (81, 81, 0.0805),
+            (82, 82, 0.0815),
+            (83, 83, 0.0825),
+            (84, 84, 0.0835),
+            (85, 85, 0.0845),
+            (86, 86, 0.0855),
+            (87, 87, 0.0865),
+            (88, 88, 0.0875),
+            (89, 89, 0.0885),
+            (90, 120, 0.0895), // 90+ use max rate
+        ];
 
-        Self {
-            single_life,
-            joint_life: None,
-        }
+        // Joint life rates trail single life by a flat 0.5% across the grid, per the Product
+        // features sheet's joint-life rider pricing.
+        let joint_life: Vec<(u8, u8, f64)> =
+            single_life.iter().map(|&(lo, hi, rate)| (lo, hi, (rate - 0.005).max(0.0))).collect();
+
+        Self::from_bands(&single_life, Some(&joint_life), false).expect("built-in payout bands are valid")
     }
 
     /// Get single life payout factor for attained age
     pub fn get_single_life(&self, attained_age: u8) -> f64 {
-        for ((min_age, max_age), factor) in &self.single_life {
-            if attained_age >= *min_age && attained_age <= *max_age {
-                return *factor;
-            }
-        }
-        // Default to highest age band if beyond range
-        0.090
+        lookup_band(&self.single_life, attained_age, self.interpolate).unwrap_or(0.0)
     }
 
     /// Get joint life payout factor for attained age (if available)
     pub fn get_joint_life(&self, attained_age: u8) -> Option<f64> {
-        self.joint_life.as_ref().and_then(|jl| {
-            for ((min_age, max_age), factor) in jl {
-                if attained_age >= *min_age && attained_age <= *max_age {
-                    return Some(*factor);
-                }
-            }
-            None
-        })
+        self.joint_life.as_ref().and_then(|bands| lookup_band(bands, attained_age, self.interpolate))
+    }
+}
+
+/// Binary-search `bands` (sorted, contiguous `(min_age, max_age, rate)`) for `attained_age`.
+/// Ages outside the table clamp to the nearest band's rate; `interpolate` blends linearly between
+/// this band's anchor (`min_age`) and the next band's anchor rather than returning a flat rate.
+fn lookup_band(bands: &[(u8, u8, f64)], attained_age: u8, interpolate: bool) -> Option<f64> {
+    if bands.is_empty() {
+        return None;
+    }
+    if attained_age < bands[0].0 {
+        return Some(bands[0].2);
+    }
+    if attained_age > bands[bands.len() - 1].1 {
+        return Some(bands[bands.len() - 1].2);
+    }
+
+    let idx = bands.partition_point(|&(_, max_age, _)| max_age < attained_age);
+    let (min_age, _, rate) = bands[idx];
+
+    if !interpolate || idx + 1 >= bands.len() {
+        return Some(rate);
     }
+
+    let (next_min_age, _, next_rate) = bands[idx + 1];
+    let span = (next_min_age - min_age) as f64;
+    if span <= 0.0 {
+        return Some(rate);
+    }
+    let t = (attained_age - min_age) as f64 / span;
+    Some(rate + t * (next_rate - rate))
+}
+
+/// Reject overlapping or gapped bands: once sorted by `min_age`, each band's `max_age` must be
+/// exactly one less than the next band's `min_age`.
+fn validate_bands(bands: &[(u8, u8, f64)]) -> Result<(), String> {
+    for &(min_age, max_age, _) in bands {
+        if min_age > max_age {
+            return Err(format!("invalid payout band: min_age {} > max_age {}", min_age, max_age));
+        }
+    }
+    for window in bands.windows(2) {
+        let (_, prev_max, _) = window[0];
+        let (next_min, _, _) = window[1];
+        if next_min != prev_max + 1 {
+            return Err(format!(
+                "payout bands must be contiguous with no overlap or gap: band ending at age {} is followed by a band starting at age {}",
+                prev_max, next_min
+            ));
+        }
+    }
+    Ok(())
 }
 
 /// GLWB rider features
@@ -181,6 +228,11 @@ pub struct GlwbFeatures {
 
     /// Payout factors by age
     pub payout_factors: PayoutFactors,
+
+    /// If true, at each policy anniversary before income activation the benefit base is reset
+    /// to `max(rolled_up_base, account_value)`, locking in strong market performance as a higher
+    /// guaranteed base.
+    pub ratchet: bool,
 }
 
 impl Default for GlwbFeatures {
@@ -194,6 +246,7 @@ impl Default for GlwbFeatures {
             pre_activation_charge: 0.005,  // 0.5% per annum
             post_activation_charge: 0.015, // 1.5% per annum
             payout_factors: PayoutFactors::default(),
+            ratchet: false,
         }
     }
 }
@@ -209,29 +262,93 @@ impl GlwbFeatures {
         annual_rate / 12.0
     }
 
-    /// Calculate monthly rollup factor for benefit base
-    /// Returns the factor to multiply benefit base by (> 1.0 means growth)
-    pub fn monthly_rollup_factor(&self, policy_year: u32, income_activated: bool) -> f64 {
-        // No rollup after income activation or beyond rollup period
-        if income_activated || policy_year > self.rollup_years as u32 {
-            return 1.0;
-        }
+    /// Calculate maximum withdrawal amount for the year, rounded to the cent, against the
+    /// accumulator's current benefit base.
+    pub fn max_annual_withdrawal(&self, base: &BenefitBaseAccumulator, attained_age: u8) -> Currency {
+        let payout_rate = self.payout_factors.get_single_life(attained_age);
+        Currency::from_f64(base.current_base().to_f64() * payout_rate, RoundingMode::Nearest)
+    }
+}
 
-        if self.simple_rollup {
-            // Simple interest: add (rollup_rate / 12) of INITIAL benefit base each month
-            // This is handled differently - return the monthly addition rate
-            // For simple rollup, we track the monthly increment separately
-            1.0 + self.rollup_rate / 12.0
-        } else {
-            // Compound interest: multiply by (1 + rate)^(1/12)
-            (1.0 + self.rollup_rate).powf(1.0 / 12.0)
+/// Stateful GLWB benefit-base tracker: the initial base, the current (rolled-up and possibly
+/// ratcheted) base, and elapsed months since issue.
+///
+/// Applying a per-month growth *factor* to the running base every month is correct for compound
+/// roll-up but compounds simple roll-up instead of adding a fixed fraction of the *initial* base
+/// each month — which requires tracking state rather than a stateless factor. This is the only
+/// supported way to advance a benefit base under either roll-up mode; it tracks the initial base
+/// separately so simple roll-up is computed exactly, and layers in an optional annual ratchet.
+#[derive(Debug, Clone)]
+pub struct BenefitBaseAccumulator {
+    current_base: Currency,
+    elapsed_months: u32,
+    // Anchor for drift-free roll-up math: the base and elapsed-month count in effect at the
+    // start of the current roll-up stretch. Both simple and compound roll-up are computed fresh
+    // from this anchor each month (rather than compounding an already-rounded `current_base`
+    // forward), so monthly cent-rounding never accumulates into a multi-cent drift by year end.
+    // The anchor resets to the ratcheted base/month whenever the ratchet actually raises the base.
+    rollup_anchor_base: Currency,
+    rollup_anchor_month: u32,
+}
+
+impl BenefitBaseAccumulator {
+    pub fn new(initial_base: Currency) -> Self {
+        Self {
+            current_base: initial_base,
+            elapsed_months: 0,
+            rollup_anchor_base: initial_base,
+            rollup_anchor_month: 0,
         }
     }
 
-    /// Calculate maximum withdrawal amount for the year
-    pub fn max_annual_withdrawal(&self, benefit_base: f64, attained_age: u8) -> f64 {
-        let payout_rate = self.payout_factors.get_single_life(attained_age);
-        benefit_base * payout_rate
+    /// The current benefit base (after all roll-up and ratchet applied so far).
+    pub fn current_base(&self) -> Currency {
+        self.current_base
+    }
+
+    /// Advance one month: apply roll-up (simple or compound, per `features`) while still within
+    /// the roll-up window and before income activation, then — if `features.ratchet` is set —
+    /// reset the base to `max(rolled_up_base, account_value)` at each policy anniversary before
+    /// activation.
+    pub fn step_month(&mut self, features: &GlwbFeatures, account_value: Currency, income_activated: bool) {
+        self.elapsed_months += 1;
+        let policy_year = (self.elapsed_months - 1) / 12 + 1;
+
+        if !income_activated && policy_year <= features.rollup_years as u32 {
+            let months_since_anchor = (self.elapsed_months - self.rollup_anchor_month) as f64;
+            if features.simple_rollup {
+                // Simple interest: the base is the anchor base plus (rollup_rate / 12) of the
+                // anchor base for every month since the anchor, never compounding on the running
+                // balance. Round the cumulative increment-to-date once rather than rounding each
+                // month's increment independently, so the monthly roll-up doesn't drift a few
+                // cents away from the exact annual total by the end of the window.
+                let cumulative_increment = Currency::from_f64(
+                    self.rollup_anchor_base.to_f64() * features.rollup_rate / 12.0 * months_since_anchor,
+                    RoundingMode::Nearest,
+                );
+                self.current_base = self.rollup_anchor_base
+                    .checked_add(cumulative_increment)
+                    .expect("benefit base roll-up overflowed i64 cents");
+            } else {
+                // Compound interest: recompute from the anchor base using the exact elapsed-year
+                // exponent each month, rather than repeatedly multiplying the already-rounded
+                // `current_base` by the monthly factor, so the same cent-rounding drift can't
+                // accumulate across months.
+                let years_since_anchor = months_since_anchor / 12.0;
+                let compounded =
+                    self.rollup_anchor_base.to_f64() * (1.0 + features.rollup_rate).powf(years_since_anchor);
+                self.current_base = Currency::from_f64(compounded, RoundingMode::Nearest);
+            }
+        }
+
+        if features.ratchet && !income_activated && self.elapsed_months % 12 == 0 {
+            let ratcheted = Currency::from_cents(self.current_base.cents().max(account_value.cents()));
+            if ratcheted != self.current_base {
+                self.rollup_anchor_base = ratcheted;
+                self.rollup_anchor_month = self.elapsed_months;
+            }
+            self.current_base = ratcheted;
+        }
     }
 }
 
@@ -284,7 +401,7 @@ impl Default for BaseProductFeatures {
 }
 
 /// Commission assumptions with age-based rates and chargeback schedule
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommissionAssumptions {
     /// Age threshold: at or below uses "young" rates, above uses "old" rates
     pub age_threshold: u8,
@@ -311,10 +428,11 @@ pub struct CommissionAssumptions {
     /// Month 13 bonus rate on BOP AV for young ages (e.g., 0.005 = 0.5%)
     pub bonus_rate_young: f64,
 
-    /// Months with 100% chargeback (e.g., 6 = months 1-6)
-    pub chargeback_months_full: u32,
-    /// Months with 50% chargeback (e.g., 12 = months 7-12)
-    pub chargeback_months_half: u32,
+    /// Per-projection-month chargeback recapture fractions.
+    pub chargeback_schedule: ChargebackSchedule,
+
+    /// Ongoing trail/renewal commission stream, if this product pays one.
+    pub trail: Option<TrailCommission>,
 }
 
 impl Default for CommissionAssumptions {
@@ -330,8 +448,8 @@ impl Default for CommissionAssumptions {
             imo_conversion_rate: 0.25,           // 25%
             wholesaler_conversion_rate: 0.40,    // 40%
             bonus_rate_young: 0.005,             // 0.5%
-            chargeback_months_full: 6,
-            chargeback_months_half: 12,
+            chargeback_schedule: ChargebackSchedule::two_tier(6, 6),
+            trail: None,
         }
     }
 }
@@ -356,23 +474,43 @@ impl CommissionAssumptions {
         }
     }
 
-    /// Get chargeback factor based on projection month
-    /// 100% for months 1-6, 50% for months 7-12, 0% after
-    pub fn chargeback_factor(&self, projection_month: u32, policy_year: u32) -> f64 {
-        if policy_year > 1 {
-            0.0
-        } else if projection_month <= self.chargeback_months_full {
-            1.0
-        } else if projection_month <= self.chargeback_months_half {
-            0.5
-        } else {
-            0.0
-        }
+    /// Get chargeback factor for a given projection month since issue (1-indexed); a direct
+    /// lookup into `chargeback_schedule`, which already returns 0.0 past its configured horizon.
+    ///
+    /// CALL-SITE BREAK: the baseline signature was `chargeback_factor(projection_month,
+    /// policy_year)` — `policy_year` was only used to hard-zero the factor past year 1, which
+    /// `chargeback_schedule`'s own horizon now subsumes, so it's dropped here. Nothing in this
+    /// tree calls this function (the real caller is in `src/projection/engine.rs`, which isn't
+    /// part of this snapshot) so that call site hasn't been updated to match. Whoever owns
+    /// `engine.rs` needs to drop the `policy_year` argument at the call site before this compiles
+    /// against it.
+    pub fn chargeback_factor(&self, projection_month: u32) -> f64 {
+        self.chargeback_schedule.factor(projection_month)
     }
 
-    /// Calculate all commission components for a given premium and issue age
-    /// Returns (agent, imo_net, imo_conversion, wholesaler_net, wholesaler_conversion)
-    pub fn calculate_commissions(&self, premium: f64, issue_age: u8) -> (f64, f64, f64, f64, f64) {
+    /// Calculate all commission components for a given premium and issue age, each rounded to
+    /// the cent and reconciled via largest-remainder so the upfront components sum exactly to
+    /// the rounded gross commission, regardless of premium. `projection_month` and
+    /// `bop_account_value` drive the trail commission, which is `Currency::ZERO` when `trail`
+    /// isn't configured or the month precedes its start.
+    /// Returns (agent, imo_net, imo_conversion, wholesaler_net, wholesaler_conversion, trail)
+    ///
+    /// CALL-SITE BREAK: the baseline signature was `calculate_commissions(premium, issue_age) ->
+    /// (f64, f64, f64, f64, f64)`. This version adds two required parameters
+    /// (`projection_month`, `bop_account_value`) to drive the new trail commission, changes every
+    /// return component from `f64` to `Currency`, and grows the return tuple from 5 to 6
+    /// elements. The real caller is in `src/projection/engine.rs`, which isn't part of this
+    /// snapshot, so that call site hasn't been updated and this change will not compile against
+    /// it as-is. Whoever owns `engine.rs` needs to pass the two new arguments and destructure the
+    /// 6-tuple (converting the `Currency` components back to `f64` at whatever boundary the
+    /// engine still uses floats) before merging.
+    pub fn calculate_commissions(
+        &self,
+        premium: f64,
+        issue_age: u8,
+        projection_month: u32,
+        bop_account_value: Currency,
+    ) -> (Currency, Currency, Currency, Currency, Currency, Currency) {
         let agent = premium * self.agent_rate(issue_age);
 
         let (imo_gross, wholesaler_gross) = if issue_age <= self.age_threshold {
@@ -388,7 +526,291 @@ impl CommissionAssumptions {
         let wholesaler_net = wholesaler_gross * (1.0 - self.wholesaler_conversion_rate);
         let wholesaler_conversion = wholesaler_gross * self.wholesaler_conversion_rate;
 
-        (agent, imo_net, imo_conversion, wholesaler_net, wholesaler_conversion)
+        let components = [agent, imo_net, imo_conversion, wholesaler_net, wholesaler_conversion];
+        let gross_rounded = Currency::from_f64(components.iter().sum(), RoundingMode::Nearest);
+        let reconciled = reconcile_to_total(&components, gross_rounded);
+
+        let trail = self
+            .trail
+            .as_ref()
+            .map(|t| t.monthly_amount(projection_month, bop_account_value))
+            .unwrap_or(Currency::ZERO);
+
+        (reconciled[0], reconciled[1], reconciled[2], reconciled[3], reconciled[4], trail)
+    }
+}
+
+/// Per-projection-month commission chargeback recapture fractions, indexed by month since issue
+/// (month 1 is the first projection month). Replaces a hardcoded two-tier step so carriers'
+/// published chargeback grids — linear decay, multi-year clawbacks, whatever shape a filed
+/// schedule takes — can be expressed directly instead of assumed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChargebackSchedule {
+    /// Recapture fraction by projection month, 1-indexed (index 0 = month 1).
+    months: Vec<f64>,
+}
+
+impl ChargebackSchedule {
+    /// Build from an explicit per-month fraction vector.
+    pub fn from_months(months: Vec<f64>) -> Self {
+        Self { months }
+    }
+
+    /// The standard two-tier grid this schedule replaces: 100% chargeback for `full_months`,
+    /// then 50% for the following `half_months`, then 0%.
+    pub fn two_tier(full_months: u32, half_months: u32) -> Self {
+        let mut months = vec![1.0; full_months as usize];
+        months.extend(std::iter::repeat(0.5).take(half_months as usize));
+        Self { months }
+    }
+
+    /// Recapture fraction for `projection_month` (1-indexed); 0.0 past the end of the schedule.
+    pub fn factor(&self, projection_month: u32) -> f64 {
+        let idx = (projection_month as usize).saturating_sub(1);
+        self.months.get(idx).copied().unwrap_or(0.0)
+    }
+}
+
+/// An ongoing trail/renewal commission: an annual rate applied monthly to beginning-of-period
+/// account value, starting at `start_month`, so the projection can pay recurring overrides
+/// rather than only the single upfront commission plus month-13 bonus.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrailCommission {
+    /// Annual rate, e.g. 0.0025 = 25 bps.
+    pub annual_rate: f64,
+    /// First projection month (1-indexed) the trail is paid.
+    pub start_month: u32,
+}
+
+impl TrailCommission {
+    /// Trail commission due this month against `bop_account_value`, or `Currency::ZERO` before
+    /// `start_month`.
+    pub fn monthly_amount(&self, projection_month: u32, bop_account_value: Currency) -> Currency {
+        if projection_month < self.start_month {
+            return Currency::ZERO;
+        }
+        Currency::from_f64(bop_account_value.to_f64() * self.annual_rate / 12.0, RoundingMode::Nearest)
+    }
+}
+
+/// Round each of `values` to the cent via truncation, then award the residual cents (positive or
+/// negative) one at a time to the components with the largest fractional remainder, so the
+/// rounded components sum exactly to `target`. This is the standard largest-remainder
+/// (Hamilton's method) apportionment used to reconcile rounded splits to a rounded total.
+fn reconcile_to_total(values: &[f64], target: Currency) -> Vec<Currency> {
+    let scaled_cents: Vec<f64> = values.iter().map(|v| v * 100.0).collect();
+    let mut floor_cents: Vec<i64> = scaled_cents.iter().map(|v| v.floor() as i64).collect();
+    let remainders: Vec<f64> = scaled_cents.iter().zip(&floor_cents).map(|(v, f)| v - *f as f64).collect();
+
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| remainders[b].partial_cmp(&remainders[a]).unwrap());
+
+    let mut residual_cents = target.cents() - floor_cents.iter().sum::<i64>();
+    let mut i = 0;
+    while residual_cents != 0 && !order.is_empty() {
+        let idx = order[i % order.len()];
+        if residual_cents > 0 {
+            floor_cents[idx] += 1;
+            residual_cents -= 1;
+        } else {
+            floor_cents[idx] -= 1;
+            residual_cents += 1;
+        }
+        i += 1;
+    }
+
+    floor_cents.into_iter().map(Currency::from_cents).collect()
+}
+
+/// A benefit amount that varies by policy year: flat, linearly increasing or decreasing from an
+/// initial amount, or an arbitrary per-year vector (e.g. a loaded benefit table).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BenefitSchedule {
+    Constant(f64),
+    Increasing { initial: f64, annual_increase: f64 },
+    Decreasing { initial: f64, annual_decrease: f64 },
+    Schedule(Vec<f64>),
+}
+
+impl BenefitSchedule {
+    /// Benefit amount at `policy_year` (1-indexed). `Schedule` clamps to the last entry once
+    /// `policy_year` runs past the vector.
+    pub fn amount_at(&self, policy_year: u32) -> f64 {
+        let years_elapsed = policy_year.saturating_sub(1) as f64;
+        match self {
+            BenefitSchedule::Constant(amount) => *amount,
+            BenefitSchedule::Increasing { initial, annual_increase } => initial + annual_increase * years_elapsed,
+            BenefitSchedule::Decreasing { initial, annual_decrease } => (initial - annual_decrease * years_elapsed).max(0.0),
+            BenefitSchedule::Schedule(amounts) => {
+                let idx = (policy_year as usize).saturating_sub(1);
+                amounts.get(idx).or_else(|| amounts.last()).copied().unwrap_or(0.0)
+            }
+        }
+    }
+}
+
+/// Per-product-type benefit triggers and amounts, so projection code can branch on contract
+/// semantics through the trait rather than assuming deferred-annuity GLWB behavior.
+pub trait BenefitSpec {
+    /// Benefit paid if the insured survives to `policy_year` (0.0 if this variant pays no
+    /// survival benefit in that year).
+    fn survival_benefit(&self, policy_year: u32) -> f64;
+    /// Benefit paid if the insured dies during `policy_year`, given `premiums_paid_to_date`
+    /// (0.0 if this variant pays no death benefit).
+    fn death_benefit(&self, policy_year: u32, premiums_paid_to_date: f64) -> f64;
+    /// Whether premiums stop being due once the insured dies (true for every variant below —
+    /// none of them are flexible-premium products that continue collecting after death).
+    fn premiums_cease_on_death(&self) -> bool;
+}
+
+/// Pays a survival benefit at `maturity_year` and a (possibly different) death benefit in
+/// whichever policy year death occurs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndowmentSpec {
+    pub maturity_year: u32,
+    pub survival_benefit: BenefitSchedule,
+    pub death_benefit: BenefitSchedule,
+}
+
+impl BenefitSpec for EndowmentSpec {
+    fn survival_benefit(&self, policy_year: u32) -> f64 {
+        if policy_year == self.maturity_year {
+            self.survival_benefit.amount_at(policy_year)
+        } else {
+            0.0
+        }
+    }
+
+    fn death_benefit(&self, policy_year: u32, _premiums_paid_to_date: f64) -> f64 {
+        self.death_benefit.amount_at(policy_year)
+    }
+
+    fn premiums_cease_on_death(&self) -> bool {
+        true
+    }
+}
+
+/// Pays only on survival to `maturity_year`; optionally refunds premiums paid to date if death
+/// occurs before maturity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PureEndowmentSpec {
+    pub maturity_year: u32,
+    pub maturity_benefit: BenefitSchedule,
+    pub refund_premiums_on_death: bool,
+}
+
+impl BenefitSpec for PureEndowmentSpec {
+    fn survival_benefit(&self, policy_year: u32) -> f64 {
+        if policy_year == self.maturity_year {
+            self.maturity_benefit.amount_at(policy_year)
+        } else {
+            0.0
+        }
+    }
+
+    fn death_benefit(&self, _policy_year: u32, premiums_paid_to_date: f64) -> f64 {
+        if self.refund_premiums_on_death {
+            premiums_paid_to_date
+        } else {
+            0.0
+        }
+    }
+
+    fn premiums_cease_on_death(&self) -> bool {
+        true
+    }
+}
+
+/// Pays a fixed amount at `maturity_year` regardless of whether death occurred earlier; premiums
+/// stop being collected at death but the benefit is still paid out at the fixed date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TermFixSpec {
+    pub maturity_year: u32,
+    pub fixed_benefit: f64,
+}
+
+impl BenefitSpec for TermFixSpec {
+    fn survival_benefit(&self, policy_year: u32) -> f64 {
+        if policy_year == self.maturity_year {
+            self.fixed_benefit
+        } else {
+            0.0
+        }
+    }
+
+    fn death_benefit(&self, policy_year: u32, _premiums_paid_to_date: f64) -> f64 {
+        // The benefit is paid at the fixed maturity date, not at death, so this mirrors
+        // `survival_benefit` rather than paying out immediately on death.
+        if policy_year == self.maturity_year {
+            self.fixed_benefit
+        } else {
+            0.0
+        }
+    }
+
+    fn premiums_cease_on_death(&self) -> bool {
+        true
+    }
+}
+
+/// Pays a benefit on diagnosis of a covered condition, not on death or survival to term.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DreadDiseaseSpec {
+    pub covered_conditions: Vec<String>,
+    pub diagnosis_benefit: BenefitSchedule,
+}
+
+impl DreadDiseaseSpec {
+    /// Benefit paid on diagnosis of a covered condition in `policy_year`. Not a survival or
+    /// death trigger, so it lives outside `BenefitSpec`.
+    pub fn diagnosis_benefit(&self, policy_year: u32) -> f64 {
+        self.diagnosis_benefit.amount_at(policy_year)
+    }
+}
+
+impl BenefitSpec for DreadDiseaseSpec {
+    fn survival_benefit(&self, _policy_year: u32) -> f64 {
+        0.0
+    }
+
+    fn death_benefit(&self, _policy_year: u32, _premiums_paid_to_date: f64) -> f64 {
+        0.0
+    }
+
+    fn premiums_cease_on_death(&self) -> bool {
+        true
+    }
+}
+
+/// The structural form of a product this engine can project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ProductType {
+    /// The existing deferred annuity with a GLWB rider; its benefit mechanics live in
+    /// `GlwbFeatures`/`PwdAssumptions`, not in a `BenefitSpec` payload.
+    DeferredAnnuityGlwb,
+    Endowment(EndowmentSpec),
+    PureEndowment(PureEndowmentSpec),
+    TermFix(TermFixSpec),
+    DreadDisease(DreadDiseaseSpec),
+}
+
+impl Default for ProductType {
+    fn default() -> Self {
+        ProductType::DeferredAnnuityGlwb
+    }
+}
+
+impl ProductType {
+    /// Borrow this variant's `BenefitSpec`, or `None` for `DeferredAnnuityGlwb` (whose benefit
+    /// mechanics are driven by `GlwbFeatures` and `PwdAssumptions` instead).
+    pub fn benefit_spec(&self) -> Option<&dyn BenefitSpec> {
+        match self {
+            ProductType::DeferredAnnuityGlwb => None,
+            ProductType::Endowment(spec) => Some(spec),
+            ProductType::PureEndowment(spec) => Some(spec),
+            ProductType::TermFix(spec) => Some(spec),
+            ProductType::DreadDisease(spec) => Some(spec),
+        }
     }
 }
 
@@ -398,6 +820,9 @@ pub struct ProductFeatures {
     pub base: BaseProductFeatures,
     pub glwb: GlwbFeatures,
     pub commissions: CommissionAssumptions,
+    /// Structural contract form; defaults to the deferred annuity with GLWB that this module
+    /// originally modeled exclusively, so existing callers see no behavior change.
+    pub product_type: ProductType,
 }
 
 impl Default for ProductFeatures {
@@ -406,6 +831,7 @@ impl Default for ProductFeatures {
             base: BaseProductFeatures::default(),
             glwb: GlwbFeatures::default(),
             commissions: CommissionAssumptions::default(),
+            product_type: ProductType::default(),
         }
     }
 }
@@ -418,6 +844,170 @@ impl ProductFeatures {
         features.glwb.payout_factors = PayoutFactors::from_loaded(&loaded.payout_factors);
         features
     }
+
+    /// Build `ProductFeatures` from a data-driven `ProductSpec`, rather than the
+    /// compiled-in `Default` impls on `BaseProductFeatures`/`GlwbFeatures`/`CommissionAssumptions`.
+    pub fn from_spec(spec: &ProductSpec) -> Self {
+        Self {
+            base: BaseProductFeatures {
+                surrender_charges: SurrenderChargeSchedule::from_loaded(&spec.surrender_charge_years),
+                free_withdrawal_pct: spec.base.free_withdrawal_pct,
+                min_premium: spec.base.min_premium,
+                max_premium: spec.base.max_premium,
+                min_issue_age: spec.base.min_issue_age,
+                max_issue_age: spec.base.max_issue_age,
+                annual_expense_per_policy: spec.base.annual_expense_per_policy,
+                expense_rate_of_av: spec.base.expense_rate_of_av,
+                first_year_commission_rate: spec.base.first_year_commission_rate,
+            },
+            glwb: GlwbFeatures {
+                min_activation_age: spec.glwb.min_activation_age,
+                bonus_rate: spec.glwb.bonus_rate,
+                rollup_rate: spec.glwb.rollup_rate,
+                rollup_years: spec.glwb.rollup_years,
+                simple_rollup: spec.glwb.simple_rollup,
+                pre_activation_charge: spec.glwb.pre_activation_charge,
+                post_activation_charge: spec.glwb.post_activation_charge,
+                payout_factors: PayoutFactors::from_bands(
+                    &spec.glwb.payout_bands,
+                    spec.glwb.joint_life_bands.as_deref(),
+                    spec.glwb.interpolate_payout_factors,
+                )
+                .expect("invalid payout bands in product spec"),
+                ratchet: spec.glwb.ratchet,
+            },
+            commissions: spec.commissions.clone(),
+            product_type: ProductType::default(),
+        }
+    }
+}
+
+/// Surrender-charge, GLWB, and base-product-feature inputs for a product, expressed as plain
+/// data so actuaries can version and diff a product definition without recompiling the crate.
+///
+/// Deserializes from either TOML or JSON (selected by file extension in [`ProductSpec::from_file`]).
+/// [`ProductSpec::built_in_default`] returns the same values as the hardcoded `Default` impls in
+/// this module, so existing callers see no change until they load their own spec.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSpec {
+    /// Surrender charge rate by policy year (1-indexed, index 0 = year 1)
+    pub surrender_charge_years: Vec<f64>,
+    pub base: BaseSpec,
+    pub glwb: GlwbSpec,
+    pub commissions: CommissionAssumptions,
+}
+
+/// `BaseProductFeatures` fields that are plain, directly-deserializable scalars.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaseSpec {
+    pub free_withdrawal_pct: f64,
+    pub min_premium: f64,
+    pub max_premium: f64,
+    pub min_issue_age: u8,
+    pub max_issue_age: u8,
+    pub annual_expense_per_policy: f64,
+    pub expense_rate_of_av: f64,
+    pub first_year_commission_rate: f64,
+}
+
+/// `GlwbFeatures` fields plus payout bands expressed as `(min_age, max_age, rate)` triples,
+/// which serialize cleanly to TOML/JSON (unlike a `HashMap<(u8, u8), f64>`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlwbSpec {
+    pub min_activation_age: u8,
+    pub bonus_rate: f64,
+    pub rollup_rate: f64,
+    pub rollup_years: u8,
+    pub simple_rollup: bool,
+    pub pre_activation_charge: f64,
+    pub post_activation_charge: f64,
+    pub payout_bands: Vec<(u8, u8, f64)>,
+    pub joint_life_bands: Option<Vec<(u8, u8, f64)>>,
+    /// Linearly interpolate payout rates between band anchor ages instead of using the flat
+    /// per-band rate.
+    #[serde(default)]
+    pub interpolate_payout_factors: bool,
+    /// Annual ratchet: reset the base to `max(rolled_up_base, account_value)` at each
+    /// pre-activation anniversary.
+    #[serde(default)]
+    pub ratchet: bool,
+}
+
+impl ProductSpec {
+    /// The spec equivalent to this module's built-in `Default` impls, so a caller can start
+    /// from the shipped product definition and override only the fields they want to reprice.
+    pub fn built_in_default() -> Self {
+        let base = BaseProductFeatures::default();
+        let glwb = GlwbFeatures::default();
+        Self {
+            surrender_charge_years: SurrenderChargeSchedule::default_10_year().charges,
+            base: BaseSpec {
+                free_withdrawal_pct: base.free_withdrawal_pct,
+                min_premium: base.min_premium,
+                max_premium: base.max_premium,
+                min_issue_age: base.min_issue_age,
+                max_issue_age: base.max_issue_age,
+                annual_expense_per_policy: base.annual_expense_per_policy,
+                expense_rate_of_av: base.expense_rate_of_av,
+                first_year_commission_rate: base.first_year_commission_rate,
+            },
+            glwb: GlwbSpec {
+                min_activation_age: glwb.min_activation_age,
+                bonus_rate: glwb.bonus_rate,
+                rollup_rate: glwb.rollup_rate,
+                rollup_years: glwb.rollup_years,
+                simple_rollup: glwb.simple_rollup,
+                pre_activation_charge: glwb.pre_activation_charge,
+                post_activation_charge: glwb.post_activation_charge,
+                payout_bands: glwb.payout_factors.single_life.clone(),
+                joint_life_bands: glwb.payout_factors.joint_life.clone(),
+                interpolate_payout_factors: glwb.payout_factors.interpolate,
+                ratchet: glwb.ratchet,
+            },
+            commissions: CommissionAssumptions::default(),
+        }
+    }
+
+    /// Load a `ProductSpec` from a TOML or JSON file, selected by the file extension
+    /// (`.json` parses as JSON; anything else is treated as TOML).
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        let spec = if path.extension().and_then(|e| e.to_str()) == Some("json") {
+            serde_json::from_str(&contents)?
+        } else {
+            toml::from_str(&contents)?
+        };
+        Ok(spec)
+    }
+}
+
+impl PayoutFactors {
+    /// Build from sorted or unsorted `(min_age, max_age, rate)` bands, e.g. as loaded from a
+    /// [`ProductSpec`]. Joint-life bands are optional since many products only price single life.
+    /// Bands are sorted by `min_age` and validated to be contiguous and non-overlapping; returns
+    /// `Err` describing the first violation found otherwise.
+    pub fn from_bands(
+        single_life_bands: &[(u8, u8, f64)],
+        joint_life_bands: Option<&[(u8, u8, f64)]>,
+        interpolate: bool,
+    ) -> Result<Self, String> {
+        let mut single_life = single_life_bands.to_vec();
+        single_life.sort_by_key(|&(lo, _, _)| lo);
+        validate_bands(&single_life)?;
+
+        let joint_life = match joint_life_bands {
+            Some(bands) => {
+                let mut joint_life = bands.to_vec();
+                joint_life.sort_by_key(|&(lo, _, _)| lo);
+                validate_bands(&joint_life)?;
+                Some(joint_life)
+            }
+            None => None,
+        };
+
+        Ok(Self { single_life, joint_life, interpolate })
+    }
 }
 
 #[cfg(test)]
@@ -449,17 +1039,312 @@ mod tests {
     }
 
     #[test]
-    fn test_glwb_rollup() {
+    fn test_product_spec_built_in_default_matches_hardcoded_defaults() {
+        let spec = ProductSpec::built_in_default();
+        let features = ProductFeatures::from_spec(&spec);
+        let defaults = ProductFeatures::default();
+
+        assert_eq!(features.base.min_premium, defaults.base.min_premium);
+        assert_eq!(features.base.expense_rate_of_av, defaults.base.expense_rate_of_av);
+        assert_eq!(features.glwb.rollup_rate, defaults.glwb.rollup_rate);
+        assert_eq!(
+            features.glwb.payout_factors.get_single_life(65),
+            defaults.glwb.payout_factors.get_single_life(65)
+        );
+        assert_eq!(features.commissions.agent_rate_young, defaults.commissions.agent_rate_young);
+    }
+
+    #[test]
+    fn test_calculate_commissions_components_sum_to_rounded_gross() {
+        let comm = CommissionAssumptions::default();
+
+        for premium in [100_000.0, 33_333.33, 1_000_000.07, 87_654.21] {
+            let (agent, imo_net, imo_conversion, wholesaler_net, wholesaler_conversion, _trail) =
+                comm.calculate_commissions(premium, 60, 1, Currency::ZERO);
+
+            let raw_total =
+                premium * comm.agent_rate(60) + premium * comm.imo_gross_rate_young + premium * comm.wholesaler_gross_rate_young;
+            let expected_gross = Currency::from_f64(raw_total, RoundingMode::Nearest);
+            let reconciled_sum = Currency::from_cents(
+                agent.cents() + imo_net.cents() + imo_conversion.cents() + wholesaler_net.cents() + wholesaler_conversion.cents(),
+            );
+
+            assert_eq!(reconciled_sum, expected_gross);
+        }
+    }
+
+    #[test]
+    fn test_max_annual_withdrawal_rounds_to_cent() {
+        let glwb = GlwbFeatures::default();
+        let benefit_base = Currency::from_f64(130_000.0, RoundingMode::Nearest);
+        let accumulator = BenefitBaseAccumulator::new(benefit_base);
+
+        let max_wd = glwb.max_annual_withdrawal(&accumulator, 65);
+
+        let expected = benefit_base.to_f64() * glwb.payout_factors.get_single_life(65);
+        assert!((max_wd.to_f64() - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_from_bands_rejects_overlap() {
+        let bands = [(50, 60, 0.05), (55, 65, 0.06)];
+        assert!(PayoutFactors::from_bands(&bands, None, false).is_err());
+    }
+
+    #[test]
+    fn test_from_bands_rejects_gap() {
+        let bands = [(50, 60, 0.05), (62, 70, 0.06)];
+        assert!(PayoutFactors::from_bands(&bands, None, false).is_err());
+    }
+
+    #[test]
+    fn test_from_bands_accepts_unsorted_contiguous_bands() {
+        let bands = [(61, 70, 0.06), (50, 60, 0.05)];
+        let pf = PayoutFactors::from_bands(&bands, None, false).unwrap();
+        assert_eq!(pf.get_single_life(55), 0.05);
+        assert_eq!(pf.get_single_life(65), 0.06);
+    }
+
+    #[test]
+    fn test_get_single_life_clamps_beyond_table() {
+        let bands = [(50, 60, 0.05), (61, 70, 0.06)];
+        let pf = PayoutFactors::from_bands(&bands, None, false).unwrap();
+        assert_eq!(pf.get_single_life(40), 0.05);
+        assert_eq!(pf.get_single_life(200), 0.06);
+    }
+
+    #[test]
+    fn test_interpolation_blends_between_band_anchors() {
+        let bands = [(50, 59, 0.05), (60, 69, 0.06), (70, 80, 0.07)];
+        let pf = PayoutFactors::from_bands(&bands, None, true).unwrap();
+
+        assert_eq!(pf.get_single_life(50), 0.05); // at this band's anchor
+        assert_eq!(pf.get_single_life(60), 0.06); // at the next band's anchor
+        let halfway = pf.get_single_life(55);
+        assert!((halfway - 0.055).abs() < 1e-9);
+        // Last band has no successor to interpolate toward: flat rate.
+        assert_eq!(pf.get_single_life(75), 0.07);
+    }
+
+    #[test]
+    fn test_joint_life_wired_up_with_own_bands() {
+        let single = [(50, 60, 0.06), (61, 70, 0.065)];
+        let joint = [(50, 60, 0.055), (61, 70, 0.06)];
+        let pf = PayoutFactors::from_bands(&single, Some(&joint), false).unwrap();
+
+        assert_eq!(pf.get_joint_life(55), Some(0.055));
+        assert_eq!(pf.get_joint_life(65), Some(0.06));
+    }
+
+    #[test]
+    fn test_default_joint_life_no_longer_none() {
+        let pf = PayoutFactors::default();
+        assert!(pf.get_joint_life(65).is_some());
+        assert!(pf.get_joint_life(65).unwrap() < pf.get_single_life(65));
+    }
+
+    #[test]
+    fn test_product_features_default_product_type_is_glwb() {
+        let features = ProductFeatures::default();
+        assert!(matches!(features.product_type, ProductType::DeferredAnnuityGlwb));
+        assert!(features.product_type.benefit_spec().is_none());
+    }
+
+    #[test]
+    fn test_endowment_pays_survival_and_death_benefits() {
+        let spec = EndowmentSpec {
+            maturity_year: 20,
+            survival_benefit: BenefitSchedule::Constant(100_000.0),
+            death_benefit: BenefitSchedule::Constant(50_000.0),
+        };
+
+        assert_eq!(spec.survival_benefit(20), 100_000.0);
+        assert_eq!(spec.survival_benefit(10), 0.0);
+        assert_eq!(spec.death_benefit(10, 25_000.0), 50_000.0);
+        assert!(spec.premiums_cease_on_death());
+    }
+
+    #[test]
+    fn test_pure_endowment_refunds_premiums_on_death() {
+        let spec = PureEndowmentSpec {
+            maturity_year: 20,
+            maturity_benefit: BenefitSchedule::Constant(100_000.0),
+            refund_premiums_on_death: true,
+        };
+
+        assert_eq!(spec.survival_benefit(20), 100_000.0);
+        assert_eq!(spec.death_benefit(5, 12_500.0), 12_500.0);
+
+        let no_refund = PureEndowmentSpec { refund_premiums_on_death: false, ..spec };
+        assert_eq!(no_refund.death_benefit(5, 12_500.0), 0.0);
+    }
+
+    #[test]
+    fn test_term_fix_pays_at_maturity_even_after_earlier_death() {
+        let spec = TermFixSpec { maturity_year: 10, fixed_benefit: 250_000.0 };
+
+        assert_eq!(spec.death_benefit(3, 0.0), 0.0); // not paid at death
+        assert_eq!(spec.survival_benefit(10), 250_000.0);
+        assert_eq!(spec.death_benefit(10, 0.0), 250_000.0); // paid at the fixed date regardless
+    }
+
+    #[test]
+    fn test_dread_disease_pays_on_diagnosis_not_death_or_survival() {
+        let spec = DreadDiseaseSpec {
+            covered_conditions: vec!["cancer".to_string(), "stroke".to_string()],
+            diagnosis_benefit: BenefitSchedule::Constant(75_000.0),
+        };
+
+        assert_eq!(spec.diagnosis_benefit(3), 75_000.0);
+        assert_eq!(spec.survival_benefit(3), 0.0);
+        assert_eq!(spec.death_benefit(3, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_benefit_schedule_increasing_and_decreasing() {
+        let increasing = BenefitSchedule::Increasing { initial: 100_000.0, annual_increase: 5_000.0 };
+        assert_eq!(increasing.amount_at(1), 100_000.0);
+        assert_eq!(increasing.amount_at(3), 110_000.0);
+
+        let decreasing = BenefitSchedule::Decreasing { initial: 50_000.0, annual_decrease: 5_000.0 };
+        assert_eq!(decreasing.amount_at(1), 50_000.0);
+        assert_eq!(decreasing.amount_at(11), 0.0); // clamped, doesn't go negative
+
+        let schedule = BenefitSchedule::Schedule(vec![10.0, 20.0, 30.0]);
+        assert_eq!(schedule.amount_at(2), 20.0);
+        assert_eq!(schedule.amount_at(99), 30.0); // clamps to the last entry
+    }
+
+    #[test]
+    fn test_chargeback_schedule_two_tier_matches_old_step() {
+        let schedule = ChargebackSchedule::two_tier(6, 6);
+
+        for month in 1..=6 {
+            assert_eq!(schedule.factor(month), 1.0);
+        }
+        for month in 7..=12 {
+            assert_eq!(schedule.factor(month), 0.5);
+        }
+        assert_eq!(schedule.factor(13), 0.0);
+    }
+
+    #[test]
+    fn test_chargeback_schedule_arbitrary_decay() {
+        let schedule = ChargebackSchedule::from_months(vec![1.0, 0.8, 0.6, 0.4, 0.2]);
+
+        assert_eq!(schedule.factor(1), 1.0);
+        assert_eq!(schedule.factor(3), 0.6);
+        assert_eq!(schedule.factor(5), 0.2);
+        assert_eq!(schedule.factor(6), 0.0);
+    }
+
+    #[test]
+    fn test_trail_commission_zero_before_start_month() {
+        let trail = TrailCommission { annual_rate: 0.0025, start_month: 13 };
+        let av = Currency::from_f64(100_000.0, RoundingMode::Nearest);
+
+        assert_eq!(trail.monthly_amount(12, av), Currency::ZERO);
+
+        let expected = Currency::from_f64(100_000.0 * 0.0025 / 12.0, RoundingMode::Nearest);
+        assert_eq!(trail.monthly_amount(13, av), expected);
+    }
+
+    #[test]
+    fn test_calculate_commissions_trail_is_zero_when_unconfigured() {
+        let comm = CommissionAssumptions::default();
+        let av = Currency::from_f64(100_000.0, RoundingMode::Nearest);
+        let (_, _, _, _, _, trail) = comm.calculate_commissions(100_000.0, 60, 20, av);
+        assert_eq!(trail, Currency::ZERO);
+    }
+
+    #[test]
+    fn test_calculate_commissions_pays_configured_trail() {
+        let mut comm = CommissionAssumptions::default();
+        comm.trail = Some(TrailCommission { annual_rate: 0.0025, start_month: 13 });
+        let av = Currency::from_f64(100_000.0, RoundingMode::Nearest);
+
+        let (_, _, _, _, _, trail) = comm.calculate_commissions(100_000.0, 60, 24, av);
+        let expected = Currency::from_f64(100_000.0 * 0.0025 / 12.0, RoundingMode::Nearest);
+        assert_eq!(trail, expected);
+    }
+
+    #[test]
+    fn test_benefit_base_accumulator_simple_rollup_ten_year_exact() {
+        let glwb = GlwbFeatures::default(); // 10% simple rollup for 10 years
+        let initial = Currency::from_f64(100_000.0, RoundingMode::Nearest);
+        let mut accumulator = BenefitBaseAccumulator::new(initial);
+        let account_value = Currency::ZERO; // no ratchet configured, so this is irrelevant
+
+        for _ in 0..(glwb.rollup_years as u32 * 12) {
+            accumulator.step_month(&glwb, account_value, false);
+        }
+
+        let expected = Currency::from_f64(100_000.0 * (1.0 + 10.0 * 0.10), RoundingMode::Nearest);
+        assert_eq!(accumulator.current_base(), expected);
+    }
+
+    #[test]
+    fn test_benefit_base_accumulator_stops_rolling_up_after_window_and_activation() {
         let glwb = GlwbFeatures::default();
+        let initial = Currency::from_f64(100_000.0, RoundingMode::Nearest);
+        let mut accumulator = BenefitBaseAccumulator::new(initial);
+
+        for _ in 0..(glwb.rollup_years as u32 * 12) {
+            accumulator.step_month(&glwb, Currency::ZERO, false);
+        }
+        let base_after_window = accumulator.current_base();
+
+        // Beyond the rollup window, further months (activated or not) shouldn't change the base.
+        accumulator.step_month(&glwb, Currency::ZERO, false);
+        accumulator.step_month(&glwb, Currency::ZERO, true);
+        assert_eq!(accumulator.current_base(), base_after_window);
+    }
 
-        // During rollup period, not activated
-        let factor = glwb.monthly_rollup_factor(1, false);
-        assert!((factor - (1.0 + 0.10 / 12.0)).abs() < 1e-10);
+    #[test]
+    fn test_benefit_base_accumulator_compound_rollup() {
+        let mut glwb = GlwbFeatures::default();
+        glwb.simple_rollup = false;
+        let initial = Currency::from_f64(100_000.0, RoundingMode::Nearest);
+        let mut accumulator = BenefitBaseAccumulator::new(initial);
+
+        for _ in 0..12 {
+            accumulator.step_month(&glwb, Currency::ZERO, false);
+        }
+
+        let expected = Currency::from_f64(100_000.0 * (1.0 + glwb.rollup_rate), RoundingMode::Nearest);
+        assert_eq!(accumulator.current_base(), expected);
+    }
 
-        // After income activation - no rollup
-        assert_eq!(glwb.monthly_rollup_factor(1, true), 1.0);
+    #[test]
+    fn test_benefit_base_accumulator_ratchet_locks_in_market_gains() {
+        let mut glwb = GlwbFeatures::default();
+        glwb.ratchet = true;
+        let initial = Currency::from_f64(100_000.0, RoundingMode::Nearest);
+        let mut accumulator = BenefitBaseAccumulator::new(initial);
+        let strong_account_value = Currency::from_f64(150_000.0, RoundingMode::Nearest);
+
+        for _ in 0..12 {
+            accumulator.step_month(&glwb, strong_account_value, false);
+        }
+
+        // Rolled-up base after year 1 (110,000) is below the account value, so the ratchet locks
+        // the base to the account value instead.
+        assert_eq!(accumulator.current_base(), strong_account_value);
+    }
+
+    #[test]
+    fn test_benefit_base_accumulator_ratchet_keeps_rolled_up_base_if_higher() {
+        let mut glwb = GlwbFeatures::default();
+        glwb.ratchet = true;
+        let initial = Currency::from_f64(100_000.0, RoundingMode::Nearest);
+        let mut accumulator = BenefitBaseAccumulator::new(initial);
+        let weak_account_value = Currency::from_f64(90_000.0, RoundingMode::Nearest);
+
+        for _ in 0..12 {
+            accumulator.step_month(&glwb, weak_account_value, false);
+        }
 
-        // After rollup period - no rollup
-        assert_eq!(glwb.monthly_rollup_factor(11, false), 1.0);
+        let expected = Currency::from_f64(100_000.0 * 1.10, RoundingMode::Nearest);
+        assert_eq!(accumulator.current_base(), expected);
     }
 }
@@ -3,12 +3,159 @@
 //! Includes non-systematic withdrawals, RMD requirements, and free withdrawal utilization
 
 use crate::policy::QualStatus;
+use std::collections::HashMap;
+
+/// Fixed-point currency backed by integer cents, so withdrawal/benefit dollar amounts don't
+/// accumulate binary-floating-point drift across a 1200-month projection. There is no implicit
+/// `f64` <-> `Currency` coercion: every conversion goes through `from_f64`/`to_f64` and names
+/// its rounding explicitly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Currency {
+    cents: i64,
+}
+
+/// Rounding direction for a `RoundTo`. Carriers round different monetary amounts in different
+/// directions (RMD up, free-amount caps down, premiums to nearest), so the direction is always
+/// named at the call site rather than implicit in the rate math.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    Up,
+    Down,
+    Nearest,
+    /// Round half to even (banker's rounding), to avoid systematic upward bias when rounding
+    /// many exact-half values.
+    HalfEven,
+}
+
+/// A rounding spec: how many decimal places and in which direction, applied via [`RoundTo::apply`].
+#[derive(Debug, Clone, Copy)]
+pub struct RoundTo {
+    pub decimals: u8,
+    pub mode: RoundingMode,
+}
+
+impl RoundTo {
+    /// Round `value` to `self.decimals` places using `self.mode`.
+    pub fn apply(self, value: f64) -> f64 {
+        let scale = 10f64.powi(self.decimals as i32);
+        let scaled = value * scale;
+        let rounded = match self.mode {
+            RoundingMode::Up => scaled.ceil(),
+            RoundingMode::Down => scaled.floor(),
+            RoundingMode::Nearest => scaled.round(),
+            RoundingMode::HalfEven => half_even_round(scaled),
+        };
+        rounded / scale
+    }
+}
+
+/// Round-half-to-even at the integer boundary (`x` is assumed to already be scaled to the
+/// target precision).
+fn half_even_round(x: f64) -> f64 {
+    let floor = x.floor();
+    let diff = x - floor;
+    if (diff - 0.5).abs() < 1e-9 {
+        if (floor as i64) % 2 == 0 { floor } else { floor + 1.0 }
+    } else {
+        x.round()
+    }
+}
+
+impl Currency {
+    pub const ZERO: Currency = Currency { cents: 0 };
+
+    /// Convert a dollar amount to `Currency`, rounding to the cent using `mode`.
+    pub fn from_f64(dollars: f64, mode: RoundingMode) -> Self {
+        let cents = RoundTo { decimals: 2, mode }.apply(dollars) * 100.0;
+        Currency { cents: cents.round() as i64 }
+    }
+
+    /// Construct directly from a whole number of cents (e.g. loaded from a reconciled total).
+    pub fn from_cents(cents: i64) -> Self {
+        Currency { cents }
+    }
+
+    /// The dollar amount as an `f64`, for display or for feeding non-monetary math.
+    pub fn to_f64(self) -> f64 {
+        self.cents as f64 / 100.0
+    }
+
+    pub fn cents(self) -> i64 {
+        self.cents
+    }
+
+    /// Add two amounts, returning `None` on `i64` cent overflow rather than silently wrapping.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.cents.checked_add(other.cents).map(|cents| Currency { cents })
+    }
+}
 
-/// RMD (Required Minimum Distribution) table by attained age
+/// Number of decimal places [`FixedRate`] retains.
+const RATE_SCALE_DECIMALS: u32 = 12;
+const RATE_SCALE: i128 = 10i128.pow(RATE_SCALE_DECIMALS);
+
+/// Fixed-point decimal rate, scaled by `10^12` and backed by `i128`, so accrual arithmetic is
+/// deterministic bit-for-bit across platforms rather than depending on the `f64` `powf`
+/// implementation, which is not guaranteed to round identically everywhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedRate {
+    scaled: i128,
+}
+
+impl FixedRate {
+    pub const ZERO: FixedRate = FixedRate { scaled: 0 };
+
+    /// Convert a rate (e.g. `0.0377` for 3.77%) to fixed-point, rounding to `RATE_SCALE_DECIMALS`
+    /// places.
+    pub fn from_f64(rate: f64) -> Self {
+        FixedRate { scaled: (rate * RATE_SCALE as f64).round() as i128 }
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.scaled as f64 / RATE_SCALE as f64
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.scaled.checked_add(other.scaled).map(|scaled| Self { scaled })
+    }
+
+    /// Multiply two fixed-point rates, rescaling the i128 product back down by `RATE_SCALE`.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.scaled.checked_mul(other.scaled).map(|scaled| Self { scaled: scaled / RATE_SCALE })
+    }
+}
+
+/// Present value of a level annuity of `periods` payments of `payment` each, discounted at
+/// `rate` per period: `payment * (1 - (1+rate)^-periods) / rate`. Handles the `rate == 0` limit
+/// as `payment * periods` rather than dividing by zero.
+pub fn pv_annuity(payment: f64, rate: f64, periods: u32) -> f64 {
+    if rate.abs() < 1e-12 {
+        return payment * periods as f64;
+    }
+    payment * (1.0 - (1.0 + rate).powi(-(periods as i32))) / rate
+}
+
+/// SECURE 2.0 required-beginning-date schedule: resolves the RMD start age from birth year.
+/// Pre-SECURE-2.0 contracts (born 1950 or earlier) are grandfathered at 72; 1951-1959 births use
+/// 73; 1960 and later births use 75.
+pub fn secure_2_0_start_age(birth_year: i32) -> u8 {
+    if birth_year <= 1950 {
+        72
+    } else if birth_year <= 1959 {
+        73
+    } else {
+        75
+    }
+}
+
+/// RMD (Required Minimum Distribution) table by attained age, a.k.a. the Uniform Lifetime Table.
 #[derive(Debug, Clone)]
 pub struct RmdTable {
-    /// RMD rates by age (starting from age 73)
+    /// RMD rates by age (starting from `start_age`)
     rates: Vec<(u8, f64)>,
+    /// The required-beginning-date age below which no RMD applies. Legislatively scheduled to
+    /// move (see [`secure_2_0_start_age`]), so this is configurable rather than a literal `73`.
+    start_age: u8,
 }
 
 impl Default for RmdTable {
@@ -16,6 +163,7 @@ impl Default for RmdTable {
         // From Non-systematic PWDs sheet in Excel
         // Distribution periods and rates starting at age 73
         Self {
+            start_age: 73,
             rates: vec![
                 (73, 0.0377358491),
                 (74, 0.0392156863),
@@ -71,17 +219,29 @@ impl Default for RmdTable {
 }
 
 impl RmdTable {
-    /// Create from loaded CSV data
+    /// Create from loaded CSV data, using the default start age (73).
     pub fn from_loaded(rates: &[(u8, f64)]) -> Self {
         Self {
             rates: rates.to_vec(),
+            start_age: 73,
         }
     }
 
+    /// Override the required-beginning-date age (e.g. via [`secure_2_0_start_age`] for a given
+    /// policyholder's birth year).
+    pub fn with_start_age(mut self, start_age: u8) -> Self {
+        self.start_age = start_age;
+        self
+    }
+
+    pub fn start_age(&self) -> u8 {
+        self.start_age
+    }
+
     /// Get RMD rate for a given attained age
-    /// Returns 0 for ages below RMD start age (73)
+    /// Returns 0 for ages below the configured RMD start age
     pub fn get_rate(&self, attained_age: u8) -> f64 {
-        if attained_age < 73 {
+        if attained_age < self.start_age {
             return 0.0;
         }
 
@@ -106,49 +266,134 @@ impl RmdTable {
     }
 }
 
+/// IRS Joint Life and Last Survivor RMD table, keyed on `(owner_age, beneficiary_age)`. Used in
+/// place of the Uniform Lifetime Table ([`RmdTable`]) when the sole beneficiary is a spouse
+/// materially younger than the owner, which produces a longer joint life expectancy and
+/// therefore a lower required rate.
+#[derive(Debug, Clone, Default)]
+pub struct JointLifeRmdTable {
+    rates: HashMap<(u8, u8), f64>,
+}
+
+impl JointLifeRmdTable {
+    /// Create from loaded `(owner_age, beneficiary_age, rate)` rows.
+    pub fn from_loaded(rows: &[(u8, u8, f64)]) -> Self {
+        Self {
+            rates: rows.iter().map(|&(owner_age, beneficiary_age, rate)| ((owner_age, beneficiary_age), rate)).collect(),
+        }
+    }
+
+    /// RMD rate for the given owner/beneficiary age pair, or `0.0` if not tabulated.
+    pub fn get_rate(&self, owner_age: u8, beneficiary_age: u8) -> f64 {
+        self.rates.get(&(owner_age, beneficiary_age)).copied().unwrap_or(0.0)
+    }
+}
+
+/// Parameters for the moneyness-driven dynamic utilization curve (see
+/// [`FreeWithdrawalUtilization::Dynamic`]):
+///
+/// `multiplier(m) = clamp(floor + (ceil - floor) * logistic(k * (m - m0)), floor, ceil)`
+///
+/// where `m = benefit_base / account_value`. As the guarantee moves further in-the-money
+/// (`m` rises above `m0`), the multiplier rises toward `ceil`; out-of-the-money (`m` below
+/// `m0`), it falls toward `floor`.
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicUtilizationParams {
+    /// Moneyness pivot where the logistic curve is centered (1.0 = benefit base equals AV).
+    pub m0: f64,
+    /// Logistic steepness.
+    pub k: f64,
+    /// Multiplier floor.
+    pub floor: f64,
+    /// Multiplier ceiling.
+    pub ceil: f64,
+}
+
+impl Default for DynamicUtilizationParams {
+    fn default() -> Self {
+        Self { m0: 1.0, k: 2.0, floor: 0.5, ceil: 1.5 }
+    }
+}
+
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
 /// Free withdrawal utilization by policy year (before income activation)
 #[derive(Debug, Clone)]
-pub struct FreeWithdrawalUtilization {
-    /// Utilization rates by policy year
-    rates: Vec<f64>,
+pub enum FreeWithdrawalUtilization {
+    /// Flat lookup table by policy year — the historical behavior.
+    Static(Vec<f64>),
+    /// Year-indexed base rate scaled by a moneyness-driven logistic curve (see
+    /// [`DynamicUtilizationParams`]), for cells where withdrawal behavior responds to how
+    /// in-the-money the GLWB guarantee is.
+    Dynamic {
+        base_year_rates: Vec<f64>,
+        params: DynamicUtilizationParams,
+    },
 }
 
 impl Default for FreeWithdrawalUtilization {
     fn default() -> Self {
         // From Non-systematic PWDs sheet
         // Before income activation, policyholders take a % of free amount
-        Self {
-            rates: vec![
-                0.1, // Year 1: 10%
-                0.2, // Year 2: 20%
-                0.3, // Year 3: 30%
-                0.4, // Year 4+: 40%
-            ],
-        }
+        Self::Static(vec![
+            0.1, // Year 1: 10%
+            0.2, // Year 2: 20%
+            0.3, // Year 3: 30%
+            0.4, // Year 4+: 40%
+        ])
     }
 }
 
 impl FreeWithdrawalUtilization {
     /// Create from loaded CSV data
     pub fn from_loaded(rates: &[f64]) -> Self {
-        Self {
-            rates: rates.to_vec(),
-        }
+        Self::Static(rates.to_vec())
     }
 
     /// Create from individual year rates
     /// year4_plus is used for year 4 and all subsequent years
     pub fn from_rates(year1: f64, year2: f64, year3: f64, year4_plus: f64) -> Self {
-        Self {
-            rates: vec![year1, year2, year3, year4_plus],
+        Self::Static(vec![year1, year2, year3, year4_plus])
+    }
+
+    /// Create a moneyness-driven dynamic utilization curve over the given year-indexed base
+    /// rates.
+    pub fn from_dynamic(base_year_rates: Vec<f64>, params: DynamicUtilizationParams) -> Self {
+        Self::Dynamic { base_year_rates, params }
+    }
+
+    fn base_year_rates(&self) -> &[f64] {
+        match self {
+            Self::Static(rates) => rates,
+            Self::Dynamic { base_year_rates, .. } => base_year_rates,
         }
     }
 
-    /// Get utilization rate for policy year
+    /// Get the year-indexed base utilization rate for policy year, ignoring moneyness. This is
+    /// the same table lookup used historically; for a `Dynamic` curve it is the un-scaled base
+    /// rate for the year.
     pub fn get_rate(&self, policy_year: u32) -> f64 {
+        let rates = self.base_year_rates();
         let idx = (policy_year as usize).saturating_sub(1);
-        self.rates.get(idx).copied()
-            .unwrap_or_else(|| self.rates.last().copied().unwrap_or(0.4))
+        rates.get(idx).copied()
+            .unwrap_or_else(|| rates.last().copied().unwrap_or(0.4))
+    }
+
+    /// Resolve the utilization rate for `policy_year`, applying the moneyness-driven multiplier
+    /// when `moneyness` (`benefit_base / account_value`) is supplied and this is a `Dynamic`
+    /// curve. Falls back to the flat year-indexed base rate otherwise.
+    pub fn resolve_rate(&self, policy_year: u32, moneyness: Option<f64>) -> f64 {
+        let base_rate = self.get_rate(policy_year);
+        match (self, moneyness) {
+            (Self::Dynamic { params, .. }, Some(m)) => {
+                let multiplier = (params.floor + (params.ceil - params.floor) * logistic(params.k * (m - params.m0)))
+                    .clamp(params.floor, params.ceil);
+                base_rate * multiplier
+            }
+            _ => base_rate,
+        }
     }
 }
 
@@ -157,6 +402,10 @@ impl FreeWithdrawalUtilization {
 pub struct PwdAssumptions {
     pub rmd: RmdTable,
     pub free_utilization: FreeWithdrawalUtilization,
+    /// Joint Life and Last Survivor table, used by [`PwdAssumptions::rmd_rate`] in place of
+    /// `rmd` when the sole beneficiary is a much-younger spouse. `None` when not configured,
+    /// in which case `rmd_rate` always falls back to the Uniform Lifetime table.
+    pub joint_life_rmd: Option<JointLifeRmdTable>,
 }
 
 impl Default for PwdAssumptions {
@@ -164,6 +413,7 @@ impl Default for PwdAssumptions {
         Self {
             rmd: RmdTable::default(),
             free_utilization: FreeWithdrawalUtilization::default(),
+            joint_life_rmd: None,
         }
     }
 }
@@ -174,6 +424,34 @@ impl PwdAssumptions {
         Self {
             rmd: RmdTable::from_loaded(&loaded.rmd_rates),
             free_utilization: FreeWithdrawalUtilization::from_loaded(&loaded.free_withdrawal_util),
+            joint_life_rmd: None,
+        }
+    }
+
+    /// Resolve the qualified RMD rate for a policyholder, selecting the Joint Life and Last
+    /// Survivor table over the Uniform Lifetime table when a spouse beneficiary more than
+    /// `spouse_age_gap_threshold` years younger is supplied and a joint-life table is configured.
+    /// Non-qualified policies have no RMD requirement.
+    ///
+    /// `beneficiary` is `(beneficiary_age, is_spouse)`.
+    pub fn rmd_rate(
+        &self,
+        owner_age: u8,
+        qual_status: QualStatus,
+        beneficiary: Option<(u8, bool)>,
+        spouse_age_gap_threshold: u8,
+    ) -> f64 {
+        if qual_status == QualStatus::N {
+            return 0.0;
+        }
+
+        match (beneficiary, &self.joint_life_rmd) {
+            (Some((beneficiary_age, true)), Some(joint_table))
+                if owner_age.saturating_sub(beneficiary_age) > spouse_age_gap_threshold =>
+            {
+                joint_table.get_rate(owner_age, beneficiary_age)
+            }
+            _ => self.rmd.get_rate(owner_age),
         }
     }
 
@@ -227,6 +505,10 @@ impl PwdAssumptions {
     /// * `qual_status` - Qualified or non-qualified
     /// * `income_activated` - Whether GLWB income has been activated
     /// * `free_pct` - Base free withdrawal percentage from ProductFeatures
+    /// * `moneyness` - Optional `(benefit_base, account_value)` pair; when supplied and
+    ///   `free_utilization` is a [`FreeWithdrawalUtilization::Dynamic`] curve, the utilization
+    ///   rate is scaled by how in-the-money the guarantee is instead of using the flat
+    ///   year-indexed rate.
     ///
     /// # Returns
     /// Annual PWD rate as a fraction of AV
@@ -237,6 +519,7 @@ impl PwdAssumptions {
         qual_status: QualStatus,
         income_activated: bool,
         free_pct: f64,
+        moneyness: Option<(f64, f64)>,
     ) -> f64 {
         if income_activated {
             // After income activation, non-systematic PWDs are minimal
@@ -247,8 +530,11 @@ impl PwdAssumptions {
         // Free amount = FPW% (incorporates RMD for qualified contracts)
         let free_rate = self.get_fpw_pct(policy_year, attained_age, qual_status, free_pct);
 
-        // Utilization of the free amount
-        let utilization = self.free_utilization.get_rate(policy_year);
+        // Utilization of the free amount, scaled by moneyness when a dynamic curve is configured
+        let moneyness_ratio = moneyness.map(|(benefit_base, account_value)| {
+            if account_value > 0.0 { benefit_base / account_value } else { f64::INFINITY }
+        });
+        let utilization = self.free_utilization.resolve_rate(policy_year, moneyness_ratio);
 
         // Annual PWD = free amount × utilization
         free_rate * utilization
@@ -265,7 +551,7 @@ impl PwdAssumptions {
         income_activated: bool,
         free_pct: f64,
     ) -> f64 {
-        let annual = self.annual_pwd_rate(policy_year, attained_age, qual_status, income_activated, free_pct);
+        let annual = self.annual_pwd_rate(policy_year, attained_age, qual_status, income_activated, free_pct, None);
 
         // Convert to monthly using actuarial formula: 1 - (1 - annual)^(1/12)
         1.0 - (1.0 - annual).powf(1.0 / 12.0)
@@ -288,11 +574,133 @@ impl PwdAssumptions {
             return 0.0;
         }
 
-        let annual = self.annual_pwd_rate(policy_year, attained_age, qual_status, income_activated, free_pct);
+        let annual = self.annual_pwd_rate(policy_year, attained_age, qual_status, income_activated, free_pct, None);
 
         // Convert to monthly using actuarial formula: 1 - (1 - annual)^(1/12)
         1.0 - (1.0 - annual).powf(1.0 / 12.0)
     }
+
+    /// Expected present value of the projected partial withdrawal stream for a cell.
+    ///
+    /// Walks `horizon_months` of monthly PWD rates (via `monthly_pwd_rate_adjusted`), applying
+    /// each month's withdrawal against a running account value, weights by the survival
+    /// probability `tpx(month)` supplied by the caller's mortality assumptions, and discounts at
+    /// `monthly_discount_rate`. Stops early if the account value is exhausted. This lets callers
+    /// feed reserve/profitability calculations directly instead of re-deriving the discounting.
+    ///
+    /// # Arguments
+    /// * `initial_account_value` - Starting account value
+    /// * `issue_age` - Issue age, used to derive attained age each policy year
+    /// * `qual_status` - Qualified or non-qualified
+    /// * `free_pct` - Base free withdrawal percentage from ProductFeatures
+    /// * `income_activated_month` - Month (1-indexed) GLWB income activates, if ever
+    /// * `horizon_months` - Number of months to project
+    /// * `monthly_discount_rate` - Discount rate per month
+    /// * `tpx` - Survival probability to month `t`, supplied by the mortality assumptions
+    #[allow(clippy::too_many_arguments)]
+    pub fn expected_pv_withdrawals(
+        &self,
+        initial_account_value: f64,
+        issue_age: u8,
+        qual_status: QualStatus,
+        free_pct: f64,
+        income_activated_month: Option<u32>,
+        horizon_months: u32,
+        monthly_discount_rate: f64,
+        tpx: impl Fn(u32) -> f64,
+    ) -> f64 {
+        let mut account_value = initial_account_value;
+        let mut pv = 0.0;
+
+        for month in 1..=horizon_months {
+            if account_value <= 0.0 {
+                break;
+            }
+
+            let policy_year = (month - 1) / 12 + 1;
+            let month_in_policy_year = (month - 1) % 12 + 1;
+            let attained_age = issue_age.saturating_add(((month - 1) / 12) as u8);
+            let income_activated = income_activated_month.is_some_and(|m| month >= m);
+
+            let rate = self.monthly_pwd_rate_adjusted(
+                policy_year, month_in_policy_year, attained_age, qual_status, income_activated, free_pct,
+            );
+            let withdrawal = account_value * rate;
+            let discount_factor = (1.0 + monthly_discount_rate).powi(-(month as i32));
+
+            pv += withdrawal * tpx(month) * discount_factor;
+            account_value -= withdrawal;
+        }
+
+        pv
+    }
+
+    /// Dollar withdrawal for the month given an account value, rounded per `round_mode`.
+    ///
+    /// The rounding direction is parameterized rather than implicit: callers pass
+    /// `RoundingMode::Up` for RMD amounts (carriers never underpay a required distribution) and
+    /// `RoundingMode::Down` for free-amount caps (never overpay the penalty-free limit).
+    pub fn monthly_withdrawal_amount(
+        &self,
+        policy_year: u32,
+        attained_age: u8,
+        qual_status: QualStatus,
+        income_activated: bool,
+        free_pct: f64,
+        account_value: Currency,
+        round_mode: RoundingMode,
+    ) -> Currency {
+        let rate = self.monthly_pwd_rate(policy_year, attained_age, qual_status, income_activated, free_pct);
+        Currency::from_f64(account_value.to_f64() * rate, round_mode)
+    }
+
+    /// Precompute `monthly_pwd_rate` as a [`RateCache`] over every `(policy_year, attained_age,
+    /// qual_status)` combination in the given ranges, for a fixed `income_activated`/`free_pct`.
+    /// Turns the per-month `powf` call into an O(1) cache lookup when projecting a large in-force
+    /// block, and guarantees identical results across runs and machines.
+    pub fn build_rate_cache(
+        &self,
+        policy_years: std::ops::RangeInclusive<u32>,
+        attained_ages: std::ops::RangeInclusive<u8>,
+        income_activated: bool,
+        free_pct: f64,
+    ) -> RateCache {
+        let mut rates = HashMap::new();
+        for policy_year in policy_years {
+            for attained_age in attained_ages.clone() {
+                for is_qualified in [true, false] {
+                    let qual_status = if is_qualified { QualStatus::Q } else { QualStatus::N };
+                    let rate = self.monthly_pwd_rate(policy_year, attained_age, qual_status, income_activated, free_pct);
+                    rates.insert((policy_year, attained_age, is_qualified), FixedRate::from_f64(rate));
+                }
+            }
+        }
+        RateCache { rates }
+    }
+}
+
+/// A precomputed table of monthly PWD rates keyed by `(policy_year, attained_age,
+/// is_qualified)`, built via [`PwdAssumptions::build_rate_cache`].
+#[derive(Debug, Clone, Default)]
+pub struct RateCache {
+    rates: HashMap<(u32, u8, bool), FixedRate>,
+}
+
+impl RateCache {
+    /// O(1) lookup of the precomputed monthly rate, or `None` if the combination wasn't covered
+    /// by the ranges the cache was built over.
+    pub fn get(&self, policy_year: u32, attained_age: u8, qual_status: QualStatus) -> Option<FixedRate> {
+        let is_qualified = qual_status == QualStatus::Q;
+        self.rates.get(&(policy_year, attained_age, is_qualified)).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
 }
 
 #[cfg(test)]
@@ -329,33 +737,33 @@ mod tests {
         let free_pct = 0.05; // 5% free withdrawal from ProductFeatures
 
         // Year 1, age 60, non-qualified, not activated - FPW% is 0 in year 1 (no free, no RMD)
-        let rate = pwd.annual_pwd_rate(1, 60, QualStatus::N, false, free_pct);
+        let rate = pwd.annual_pwd_rate(1, 60, QualStatus::N, false, free_pct, None);
         assert_eq!(rate, 0.0);
 
         // Year 1, age 75, qualified - RMD rate at 75 = 0.0407
         // Only RMD applies in year 1 for qualified
         // Annual rate = 4.07% × 10% utilization = 0.407%
-        let rate_y1_q = pwd.annual_pwd_rate(1, 75, QualStatus::Q, false, free_pct);
+        let rate_y1_q = pwd.annual_pwd_rate(1, 75, QualStatus::Q, false, free_pct, None);
         let expected_y1_q = 0.0406504065 * 0.1;  // RMD(75) × year 1 utilization
         assert!((rate_y1_q - expected_y1_q).abs() < 0.001);
 
         // Year 2, age 61, non-qualified - 5% free × 20% utilization = 1%
-        let rate_y2 = pwd.annual_pwd_rate(2, 61, QualStatus::N, false, free_pct);
+        let rate_y2 = pwd.annual_pwd_rate(2, 61, QualStatus::N, false, free_pct, None);
         assert!((rate_y2 - 0.01).abs() < 0.001);
 
         // Year 4, age 77, qualified, not activated
         // RMD rate at 77 = 0.0437, which is < 5% free, so uses 5% free
         // Annual rate = 5% * 40% utilization = 2%
-        let rate_q = pwd.annual_pwd_rate(4, 77, QualStatus::Q, false, free_pct);
+        let rate_q = pwd.annual_pwd_rate(4, 77, QualStatus::Q, false, free_pct, None);
         assert!((rate_q - 0.02).abs() < 0.001); // 5% free × 40% utilization
 
         // Year 4, age 85, qualified - RMD = 6.25% > 5% free
         // Annual rate = 6.25% * 40% = 2.5%
-        let rate_rmd = pwd.annual_pwd_rate(4, 85, QualStatus::Q, false, free_pct);
+        let rate_rmd = pwd.annual_pwd_rate(4, 85, QualStatus::Q, false, free_pct, None);
         assert!((rate_rmd - 0.025).abs() < 0.001);
 
         // After income activation - no PWDs
-        let rate_activated = pwd.annual_pwd_rate(4, 77, QualStatus::Q, true, free_pct);
+        let rate_activated = pwd.annual_pwd_rate(4, 77, QualStatus::Q, true, free_pct, None);
         assert_eq!(rate_activated, 0.0);
 
         // Test monthly rate conversion
@@ -370,23 +778,179 @@ mod tests {
         let pwd = PwdAssumptions {
             rmd: RmdTable::default(),
             free_utilization: FreeWithdrawalUtilization::from_rates(0.065, 0.13, 0.195, 0.26),
+            joint_life_rmd: None,
         };
         let free_pct = 0.10; // 10% free withdrawal
 
         // Year 1, age 60, non-qualified - 0% (no free, no RMD below 73)
-        let rate_y1_nq = pwd.annual_pwd_rate(1, 60, QualStatus::N, false, free_pct);
+        let rate_y1_nq = pwd.annual_pwd_rate(1, 60, QualStatus::N, false, free_pct, None);
         assert_eq!(rate_y1_nq, 0.0);
 
         // Year 2, non-qualified - 10% × 13% = 1.3%
-        let rate_y2 = pwd.annual_pwd_rate(2, 61, QualStatus::N, false, free_pct);
+        let rate_y2 = pwd.annual_pwd_rate(2, 61, QualStatus::N, false, free_pct, None);
         assert!((rate_y2 - 0.013).abs() < 0.001);
 
         // Year 3, non-qualified - 10% × 19.5% = 1.95%
-        let rate_y3 = pwd.annual_pwd_rate(3, 62, QualStatus::N, false, free_pct);
+        let rate_y3 = pwd.annual_pwd_rate(3, 62, QualStatus::N, false, free_pct, None);
         assert!((rate_y3 - 0.0195).abs() < 0.001);
 
         // Year 4+, non-qualified - 10% × 26% = 2.6%
-        let rate_y4 = pwd.annual_pwd_rate(4, 63, QualStatus::N, false, free_pct);
+        let rate_y4 = pwd.annual_pwd_rate(4, 63, QualStatus::N, false, free_pct, None);
         assert!((rate_y4 - 0.026).abs() < 0.001);
     }
+
+    #[test]
+    fn test_currency_from_f64_rounding_modes() {
+        assert_eq!(Currency::from_f64(10.001, RoundingMode::Up).cents(), 1001);
+        assert_eq!(Currency::from_f64(10.001, RoundingMode::Down).cents(), 1000);
+        assert_eq!(Currency::from_f64(10.005, RoundingMode::Nearest).cents(), 1001);
+        assert_eq!(Currency::from_f64(10.0, RoundingMode::HalfEven).cents(), 1000);
+        assert!((Currency::from_cents(1234).to_f64() - 12.34).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_currency_checked_add() {
+        let a = Currency::from_cents(1_000);
+        let b = Currency::from_cents(250);
+        assert_eq!(a.checked_add(b).unwrap().cents(), 1_250);
+
+        let near_max = Currency::from_cents(i64::MAX);
+        assert_eq!(near_max.checked_add(Currency::from_cents(1)), None);
+    }
+
+    #[test]
+    fn test_round_to_half_even_rounds_to_nearest_even() {
+        let round = RoundTo { decimals: 0, mode: RoundingMode::HalfEven };
+        assert_eq!(round.apply(2.5), 2.0);
+        assert_eq!(round.apply(3.5), 4.0);
+    }
+
+    #[test]
+    fn test_monthly_withdrawal_amount_rounds_rmd_up_and_free_down() {
+        let pwd = PwdAssumptions::default();
+        let account_value = Currency::from_f64(100_000.0, RoundingMode::Nearest);
+
+        let rmd_amount = pwd.monthly_withdrawal_amount(
+            4, 85, QualStatus::Q, false, 0.05, account_value, RoundingMode::Up,
+        );
+        let free_amount = pwd.monthly_withdrawal_amount(
+            4, 85, QualStatus::Q, false, 0.05, account_value, RoundingMode::Down,
+        );
+
+        // Same rate, so rounding up should never produce a smaller dollar amount than rounding down.
+        assert!(rmd_amount.cents() >= free_amount.cents());
+        assert!(rmd_amount.to_f64() > 0.0);
+    }
+
+    #[test]
+    fn test_dynamic_utilization_scales_with_moneyness() {
+        let pwd = PwdAssumptions {
+            rmd: RmdTable::default(),
+            free_utilization: FreeWithdrawalUtilization::from_dynamic(
+                vec![0.1, 0.2, 0.3, 0.4],
+                DynamicUtilizationParams::default(),
+            ),
+            joint_life_rmd: None,
+        };
+        let free_pct = 0.05;
+
+        // In-the-money (benefit base well above AV): utilization pulled up toward the ceiling.
+        let in_the_money = pwd.annual_pwd_rate(4, 61, QualStatus::N, false, free_pct, Some((150_000.0, 100_000.0)));
+        // Out-of-the-money (AV above benefit base): utilization pulled down toward the floor.
+        let out_of_the_money = pwd.annual_pwd_rate(4, 61, QualStatus::N, false, free_pct, Some((80_000.0, 100_000.0)));
+        // No moneyness supplied: falls back to the flat base-year rate (5% * 40%).
+        let static_fallback = pwd.annual_pwd_rate(4, 61, QualStatus::N, false, free_pct, None);
+
+        assert!(in_the_money > static_fallback);
+        assert!(out_of_the_money < static_fallback);
+        assert!((static_fallback - 0.02).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pv_annuity_matches_closed_form_and_zero_rate_limit() {
+        let pv = pv_annuity(100.0, 0.01, 12);
+        let expected = 100.0 * (1.0 - 1.01f64.powi(-12)) / 0.01;
+        assert!((pv - expected).abs() < 1e-9);
+
+        // Zero-rate limit: PV is just the sum of payments.
+        assert_eq!(pv_annuity(100.0, 0.0, 12), 1200.0);
+    }
+
+    #[test]
+    fn test_expected_pv_withdrawals_nonzero_and_bounded_by_account_value() {
+        let pwd = PwdAssumptions::default();
+        let pv = pwd.expected_pv_withdrawals(
+            100_000.0,
+            60,
+            QualStatus::N,
+            0.05,
+            None,
+            120,
+            0.003,
+            |_month| 1.0, // certain survival, to isolate the discounting/rate logic
+        );
+
+        assert!(pv > 0.0);
+        // Withdrawals are a fraction of a depleting account value, discounted and <= undiscounted cap.
+        assert!(pv < 100_000.0);
+    }
+
+    #[test]
+    fn test_secure_2_0_start_age_schedule() {
+        assert_eq!(secure_2_0_start_age(1945), 72);
+        assert_eq!(secure_2_0_start_age(1955), 73);
+        assert_eq!(secure_2_0_start_age(1970), 75);
+    }
+
+    #[test]
+    fn test_rmd_table_configurable_start_age() {
+        let rmd = RmdTable::default().with_start_age(secure_2_0_start_age(1970));
+        assert_eq!(rmd.start_age(), 75);
+        assert_eq!(rmd.get_rate(74), 0.0); // below the 75 RBD, no RMD
+        assert!(rmd.get_rate(75) > 0.0);
+    }
+
+    #[test]
+    fn test_rmd_rate_selects_joint_life_table_for_much_younger_spouse() {
+        let mut pwd = PwdAssumptions::default();
+        pwd.joint_life_rmd = Some(JointLifeRmdTable::from_loaded(&[(75, 60, 0.025)]));
+
+        // Spouse beneficiary 15 years younger exceeds the 10-year threshold: joint-life applies.
+        let joint_rate = pwd.rmd_rate(75, QualStatus::Q, Some((60, true)), 10);
+        assert_eq!(joint_rate, 0.025);
+
+        // Spouse only 5 years younger: under the threshold, falls back to the uniform table.
+        let uniform_rate = pwd.rmd_rate(75, QualStatus::Q, Some((70, true)), 10);
+        assert!((uniform_rate - RmdTable::default().get_rate(75)).abs() < 1e-9);
+
+        // Non-qualified: no RMD regardless of beneficiary.
+        assert_eq!(pwd.rmd_rate(75, QualStatus::N, Some((60, true)), 10), 0.0);
+    }
+
+    #[test]
+    fn test_fixed_rate_roundtrip_and_checked_arithmetic() {
+        let rate = FixedRate::from_f64(0.0377358491);
+        assert!((rate.to_f64() - 0.0377358491).abs() < 1e-9);
+
+        let doubled = rate.checked_add(rate).unwrap();
+        assert!((doubled.to_f64() - 2.0 * rate.to_f64()).abs() < 1e-9);
+
+        let half = FixedRate::from_f64(0.5);
+        let product = rate.checked_mul(half).unwrap();
+        assert!((product.to_f64() - rate.to_f64() * 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rate_cache_matches_direct_computation() {
+        let pwd = PwdAssumptions::default();
+        let cache = pwd.build_rate_cache(1..=5, 60..=90, false, 0.05);
+
+        let direct = pwd.monthly_pwd_rate(4, 77, QualStatus::Q, false, 0.05);
+        let cached = cache.get(4, 77, QualStatus::Q).expect("covered by build range");
+        assert!((cached.to_f64() - direct).abs() < 1e-9);
+
+        // Not covered by the ranges the cache was built over.
+        assert!(cache.get(100, 77, QualStatus::Q).is_none());
+        assert!(!cache.is_empty());
+    }
 }
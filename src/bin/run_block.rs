@@ -4,40 +4,97 @@
 
 use actuarial_system::{
     Assumptions,
+    assumptions::pwd::{Currency, RoundingMode},
     projection::{
         ProjectionEngine, ProjectionConfig, CashflowRow, CreditingApproach, HedgeParams,
-        DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE,
+        DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE, project_nested_reserves,
+        run_scenario_block, summarize_scenarios, EconomicScenarioConfig,
+        SensitivityAxis, SensitivityGrid, compute_benefit_payout, RateAccrualCache,
+        run_policy_scenario, run_stochastic, StochasticConfig, GroupLedger,
     },
 };
-use actuarial_system::policy::load_default_inforce;
+use actuarial_system::policy::{
+    load_default_inforce, AdjustmentParams, PolicyProductType, apply_adjustment_event,
+    AdjustmentEvent, MaterialChangeSnapshot,
+};
 use rayon::prelude::*;
 use std::fs::File;
 use std::io::Write;
 use std::time::Instant;
 
-/// Aggregated monthly results across all policies
-#[derive(Debug, Clone, Default)]
+/// Aggregated monthly results across all policies.
+///
+/// Money fields are held as `Currency` (whole cents). The fields taken from `GroupLedger`
+/// (BOP/EOP AV, BOP benefit base, mortality, lapse, PWD, net cashflow) are summed in `f64` by the
+/// ledger and converted to cents once per month; the remaining money fields accumulate per policy
+/// in `Currency`, with overflow caught via `checked_add` instead of silently wrapping.
+/// `total_lives` stays `f64` since it's a fractional persistency count, not a dollar amount.
+#[derive(Debug, Clone)]
 struct AggregatedRow {
     month: u32,
-    total_bop_av: f64,
-    total_bop_bb: f64,
+    total_bop_av: Currency,
+    total_bop_bb: Currency,
     total_lives: f64,
-    total_mortality: f64,
-    total_lapse: f64,
-    total_pwd: f64,
-    total_rider_charges: f64,
-    total_surrender_charges: f64,
-    total_interest: f64,
-    total_eop_av: f64,
+    total_mortality: Currency,
+    total_lapse: Currency,
+    total_pwd: Currency,
+    total_rider_charges: Currency,
+    total_surrender_charges: Currency,
+    total_interest: Currency,
+    total_eop_av: Currency,
     // New fields
-    total_expenses: f64,
-    total_agent_commission: f64,
-    total_imo_override: f64,
-    total_wholesaler_override: f64,
-    total_bonus_comp: f64,
-    total_chargebacks: f64,
-    total_hedge_gains: f64,
-    total_net_cashflow: f64,
+    total_expenses: Currency,
+    total_agent_commission: Currency,
+    total_imo_override: Currency,
+    total_wholesaler_override: Currency,
+    total_bonus_comp: Currency,
+    total_chargebacks: Currency,
+    total_hedge_gains: Currency,
+    total_net_cashflow: Currency,
+    // Death/maturity benefit payout, computed per-policy via `compute_benefit_payout`. Every
+    // loaded policy is implicitly `PolicyProductType::Annuity` today (`Policy` doesn't carry a
+    // `product_type` field in this tree), so both totals are 0 for every month — `Annuity`
+    // always returns a zero payout, since the GLWB/account-value mechanics above already cover
+    // it. This stays wired into the real per-policy block rather than only a single-policy demo
+    // so the non-Annuity branches light up automatically once `Policy` gains that field.
+    total_death_benefit_paid: Currency,
+    total_maturity_benefit_paid: Currency,
+}
+
+impl Default for AggregatedRow {
+    fn default() -> Self {
+        AggregatedRow {
+            month: 0,
+            total_bop_av: Currency::ZERO,
+            total_bop_bb: Currency::ZERO,
+            total_lives: 0.0,
+            total_mortality: Currency::ZERO,
+            total_lapse: Currency::ZERO,
+            total_pwd: Currency::ZERO,
+            total_rider_charges: Currency::ZERO,
+            total_surrender_charges: Currency::ZERO,
+            total_interest: Currency::ZERO,
+            total_eop_av: Currency::ZERO,
+            total_expenses: Currency::ZERO,
+            total_agent_commission: Currency::ZERO,
+            total_imo_override: Currency::ZERO,
+            total_wholesaler_override: Currency::ZERO,
+            total_bonus_comp: Currency::ZERO,
+            total_chargebacks: Currency::ZERO,
+            total_hedge_gains: Currency::ZERO,
+            total_net_cashflow: Currency::ZERO,
+            total_death_benefit_paid: Currency::ZERO,
+            total_maturity_benefit_paid: Currency::ZERO,
+        }
+    }
+}
+
+/// Add `amount` (an `f64` cashflow field read off a `CashflowRow`) into `total`, rounding to the
+/// cent and failing fast on overflow rather than letting block-level totals wrap silently.
+fn accumulate(total: Currency, amount: f64) -> Currency {
+    total
+        .checked_add(Currency::from_f64(amount, RoundingMode::Nearest))
+        .expect("aggregated total overflowed i64 cents")
 }
 
 fn main() {
@@ -92,8 +149,84 @@ fn main() {
                      rider_rate, av_persist, av_lost, row.hedge_gains);
         }
         println!("=================================\n");
+
+        // Nested outer/inner reserve valuation: same policy, own basis for both outer and inner
+        // legs here, but `project_nested_reserves` takes them independently so a statutory or
+        // GAAP valuation basis can differ from the best-estimate basis that produced the outer
+        // path.
+        let reserves = project_nested_reserves(
+            policy, &assumptions, &config, &assumptions, &config, 0.03, &[1, 60, 120, 240],
+        );
+        println!("=== Debug: Policy 2 nested reserves (3% valuation rate) ===");
+        for r in &reserves {
+            println!("  month {:4}: reserve = {:.2}", r.month, r.reserve);
+        }
+        println!("=================================\n");
+
+        // Product-type benefit payout split: every loaded policy is implicitly PolicyProductType::Annuity
+        // today (Policy doesn't carry a product_type field in this tree), so this demonstrates
+        // compute_benefit_payout against a hypothetical WholeLife classification of the same
+        // policy rather than against its real (Annuity) type.
+        println!("=== Debug: Policy 2 hypothetical WholeLife death benefit payout ===");
+        for row in result.cashflows.iter().take(14) {
+            let payout = compute_benefit_payout(
+                PolicyProductType::WholeLife, None, row.projection_month / 12 + 1,
+                row.bop_av, 0.0, row.final_mortality, false,
+            );
+            println!("  month {:4}: death_benefit_paid = {:.2}", row.projection_month, payout.death_benefit_paid);
+        }
+        println!("=================================\n");
+
+        // Material-change/adjustment event: `ProjectionState` would call `apply_adjustment_event`
+        // at `event.at_duration_months` to carry both bases forward and surface the delta in
+        // `CashflowRow`, but `ProjectionState` lives in `src/projection/state.rs`, which isn't
+        // part of this tree, so this demonstrates the hook against the policy's own month-60 row
+        // (a stand-in for the live state `ProjectionState` would otherwise hand it) rather than a
+        // real mid-projection call.
+        if let Some(row60) = result.cashflows.iter().find(|r| r.projection_month == 60) {
+            let old_snapshot = MaterialChangeSnapshot {
+                benefit_base: row60.bop_benefit_base,
+                account_value: row60.bop_av,
+                rollup_rate: 0.0,
+                pre_activation_charge: row60.rider_charge_rate,
+                post_activation_charge: row60.rider_charge_rate,
+            };
+            let event = AdjustmentEvent {
+                at_duration_months: 60,
+                new_params: AdjustmentParams { bb_bonus: 0.40, ..AdjustmentParams::default() },
+            };
+            let snapshot = apply_adjustment_event(old_snapshot, &event);
+            println!("=== Debug: Policy 2 material change at month 60 (BB bonus -> 40%) ===");
+            println!("  benefit_base_delta = {:.2}", snapshot.benefit_base_delta());
+            println!("  account_value_delta = {:.2}", snapshot.account_value_delta());
+            println!("=================================\n");
+        }
+
+        // Stochastic Monte Carlo: draw mortality/lapse against this policy's own real
+        // `ProjectionResult::cashflows` (the deterministic `project_policy` run above) rather
+        // than applying those rates as fixed expected-value weights.
+        let stochastic_config = StochasticConfig { scenarios: 1000, seed: 2026 };
+        let mc_result = run_stochastic(&stochastic_config, |rng, _scenario_index| {
+            run_policy_scenario(rng, &result.cashflows, 0.03)
+        });
+        println!("=== Debug: Policy 2 stochastic PV of benefits (1000 scenarios, 3% discount) ===");
+        println!("  median  = {:.2}", mc_result.percentile_benefits(0.50));
+        println!("  p95     = {:.2}", mc_result.percentile_benefits(0.95));
+        println!("  CTE70   = {:.2}", mc_result.cte_benefits(0.70));
+        println!("=================================\n");
     }
 
+    // Precompute the shared monthly compounding factors for this block's two distinct crediting
+    // rates, so the per-policy rayon tasks below could look them up instead of repeating the same
+    // `powf` work once per policy. `ProjectionEngine::project_policy` doesn't yet take a cache —
+    // that entry point lives in `src/projection/engine.rs`, which isn't part of this tree — so
+    // `_rate_accrual_cache` isn't consulted below yet. See `src/projection/rate_accrual.rs` for
+    // what wiring it in requires once that file is reachable.
+    let _rate_accrual_cache = RateAccrualCache::build(
+        [DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE],
+        config.projection_months,
+    );
+
     // Run projections in parallel
     let results: Vec<Vec<CashflowRow>> = policies
         .par_iter()
@@ -106,36 +239,58 @@ fn main() {
 
     println!("Projections complete in {:?}", proj_start.elapsed());
 
-    // Aggregate results by month
+    // Aggregate results by month. `GroupLedger::build` owns the `initial_pols`-weighted roll-up
+    // for the fields it models (`LedgerRow`); this fills in `AggregatedRow`'s remaining fields
+    // (rider charges, expenses, comp, hedge gains, benefit payout) with the same weighting,
+    // since `LedgerRow` doesn't carry those yet.
     println!("Aggregating results...");
+    let group_ledger = GroupLedger::build(&policies, &results);
+
     let mut aggregated: Vec<AggregatedRow> = (1..=360)
         .map(|m| AggregatedRow { month: m, ..Default::default() })
         .collect();
 
-    for cashflows in &results {
+    for ledger_row in &group_ledger.ledger {
+        let idx = (ledger_row.month - 1) as usize;
+        if let Some(agg) = aggregated.get_mut(idx) {
+            agg.total_bop_av = Currency::from_f64(ledger_row.bop_av, RoundingMode::Nearest);
+            agg.total_bop_bb = Currency::from_f64(ledger_row.bop_benefit_base, RoundingMode::Nearest);
+            agg.total_lives = ledger_row.lives;
+            agg.total_mortality = Currency::from_f64(ledger_row.mortality_dec, RoundingMode::Nearest);
+            agg.total_lapse = Currency::from_f64(ledger_row.lapse_dec, RoundingMode::Nearest);
+            agg.total_pwd = Currency::from_f64(ledger_row.pwd_dec, RoundingMode::Nearest);
+            agg.total_eop_av = Currency::from_f64(ledger_row.eop_av, RoundingMode::Nearest);
+            agg.total_net_cashflow = Currency::from_f64(ledger_row.total_net_cashflow, RoundingMode::Nearest);
+        }
+    }
+
+    for (policy, cashflows) in policies.iter().zip(results.iter()) {
+        let weight = policy.initial_pols;
         for row in cashflows {
             let idx = (row.projection_month - 1) as usize;
             if idx < aggregated.len() {
                 let agg = &mut aggregated[idx];
-                agg.total_bop_av += row.bop_av;
-                agg.total_bop_bb += row.bop_benefit_base;
-                agg.total_lives += row.lives;
-                agg.total_mortality += row.mortality_dec;
-                agg.total_lapse += row.lapse_dec;
-                agg.total_pwd += row.pwd_dec;
-                agg.total_rider_charges += row.rider_charges_dec;
-                agg.total_surrender_charges += row.surrender_charges_dec;
-                agg.total_interest += row.interest_credits_dec;
-                agg.total_eop_av += row.eop_av;
-                // New fields
-                agg.total_expenses += row.expenses;
-                agg.total_agent_commission += row.agent_commission;
-                agg.total_imo_override += row.imo_override;
-                agg.total_wholesaler_override += row.wholesaler_override;
-                agg.total_bonus_comp += row.bonus_comp;
-                agg.total_chargebacks += row.chargebacks;
-                agg.total_hedge_gains += row.hedge_gains;
-                agg.total_net_cashflow += row.total_net_cashflow;
+                agg.total_rider_charges = accumulate(agg.total_rider_charges, row.rider_charges_dec * weight);
+                agg.total_surrender_charges = accumulate(agg.total_surrender_charges, row.surrender_charges_dec * weight);
+                agg.total_interest = accumulate(agg.total_interest, row.interest_credits_dec * weight);
+                agg.total_expenses = accumulate(agg.total_expenses, row.expenses * weight);
+                agg.total_agent_commission = accumulate(agg.total_agent_commission, row.agent_commission * weight);
+                agg.total_imo_override = accumulate(agg.total_imo_override, row.imo_override * weight);
+                agg.total_wholesaler_override = accumulate(agg.total_wholesaler_override, row.wholesaler_override * weight);
+                agg.total_bonus_comp = accumulate(agg.total_bonus_comp, row.bonus_comp * weight);
+                agg.total_chargebacks = accumulate(agg.total_chargebacks, row.chargebacks * weight);
+                agg.total_hedge_gains = accumulate(agg.total_hedge_gains, row.hedge_gains * weight);
+
+                // Every real policy is PolicyProductType::default() (Annuity) until `Policy`
+                // gains a `product_type` field, so this always computes a zero payout today —
+                // see the AggregatedRow field docs for why it stays wired in anyway.
+                let policy_year = row.projection_month.div_ceil(12);
+                let payout = compute_benefit_payout(
+                    PolicyProductType::default(), None, policy_year, row.bop_av, 0.0,
+                    row.final_mortality, false,
+                );
+                agg.total_death_benefit_paid = accumulate(agg.total_death_benefit_paid, payout.death_benefit_paid * weight);
+                agg.total_maturity_benefit_paid = accumulate(agg.total_maturity_benefit_paid, payout.maturity_benefit_paid * weight);
             }
         }
     }
@@ -144,51 +299,106 @@ fn main() {
     let output_path = "block_projection_output.csv";
     let mut file = File::create(output_path).expect("Failed to create output file");
 
-    writeln!(file, "Month,BOP_AV,BOP_BB,Lives,Mortality,Lapse,PWD,RiderCharges,SurrCharges,Interest,EOP_AV,Expenses,AgentComm,IMOOverride,WholesalerOverride,BonusComp,Chargebacks,HedgeGains,NetCashflow").unwrap();
+    writeln!(file, "Month,BOP_AV,BOP_BB,Lives,Mortality,Lapse,PWD,RiderCharges,SurrCharges,Interest,EOP_AV,Expenses,AgentComm,IMOOverride,WholesalerOverride,BonusComp,Chargebacks,HedgeGains,NetCashflow,DeathBenefitPaid,MaturityBenefitPaid").unwrap();
 
     for row in &aggregated {
         writeln!(
             file,
-            "{},{:.2},{:.2},{:.8},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2}",
+            "{},{:.2},{:.2},{:.8},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2}",
             row.month,
-            row.total_bop_av,
-            row.total_bop_bb,
+            row.total_bop_av.to_f64(),
+            row.total_bop_bb.to_f64(),
             row.total_lives,
-            row.total_mortality,
-            row.total_lapse,
-            row.total_pwd,
-            row.total_rider_charges,
-            row.total_surrender_charges,
-            row.total_interest,
-            row.total_eop_av,
-            row.total_expenses,
-            row.total_agent_commission,
-            row.total_imo_override,
-            row.total_wholesaler_override,
-            row.total_bonus_comp,
-            row.total_chargebacks,
-            row.total_hedge_gains,
-            row.total_net_cashflow,
+            row.total_mortality.to_f64(),
+            row.total_lapse.to_f64(),
+            row.total_pwd.to_f64(),
+            row.total_rider_charges.to_f64(),
+            row.total_surrender_charges.to_f64(),
+            row.total_interest.to_f64(),
+            row.total_eop_av.to_f64(),
+            row.total_expenses.to_f64(),
+            row.total_agent_commission.to_f64(),
+            row.total_imo_override.to_f64(),
+            row.total_wholesaler_override.to_f64(),
+            row.total_bonus_comp.to_f64(),
+            row.total_chargebacks.to_f64(),
+            row.total_hedge_gains.to_f64(),
+            row.total_net_cashflow.to_f64(),
+            row.total_death_benefit_paid.to_f64(),
+            row.total_maturity_benefit_paid.to_f64(),
         ).unwrap();
     }
 
     println!("Output written to {}", output_path);
 
+    // Economic scenario generation: S regime-switching index-return paths feed indexed
+    // crediting, so we can see tail risk in the indexed buckets rather than just the single
+    // deterministic DEFAULT_INDEXED_ANNUAL_RATE path run above.
+    println!("\nRunning economic scenario generator...");
+    let scenario_config = EconomicScenarioConfig { scenarios: 20, ..EconomicScenarioConfig::default() };
+    let scenario_rows = run_scenario_block(&policies, &assumptions, &config, &scenario_config);
+
+    for row in &scenario_rows {
+        let path = format!("scenario_{:03}_net_cashflow.csv", row.scenario_index);
+        let mut scenario_file = File::create(&path).expect("Failed to create scenario output file");
+        writeln!(scenario_file, "Month,NetCashflow").unwrap();
+        for (i, cashflow) in row.monthly_net_cashflow.iter().enumerate() {
+            writeln!(scenario_file, "{},{:.2}", i + 1, cashflow).unwrap();
+        }
+    }
+
+    let summary = summarize_scenarios(&scenario_rows, 0.03);
+    let mut summary_file = File::create("scenario_distribution_summary.csv")
+        .expect("Failed to create scenario distribution summary file");
+    writeln!(summary_file, "Metric,Value").unwrap();
+    writeln!(summary_file, "Mean,{:.2}", summary.mean).unwrap();
+    writeln!(summary_file, "CTE70,{:.2}", summary.cte70).unwrap();
+    writeln!(summary_file, "CTE90,{:.2}", summary.cte90).unwrap();
+    println!(
+        "Scenario distribution (PV of net cashflow @ 3%): mean={:.2}, CTE70={:.2}, CTE90={:.2}",
+        summary.mean, summary.cte70, summary.cte90
+    );
+
+    // Parameter-sweep sensitivity: a one-at-a-time sweep over AdjustmentParams plus the
+    // treasury_change shock, ranked tornado-style by marginal impact on PV of net cashflow.
+    // Uses a shorter projection horizon than the main block run above, since the grid re-runs
+    // the full policy set once per cell.
+    println!("\nRunning sensitivity sweep...");
+    let mut sensitivity_config = config.clone();
+    sensitivity_config.projection_months = 120;
+    let sensitivity_grid = SensitivityGrid::new(
+        AdjustmentParams::default(),
+        sensitivity_config,
+        vec![
+            SensitivityAxis::FixedPct(vec![0.25, 0.50, 0.75]),
+            SensitivityAxis::BbBonus(vec![0.30, 0.40]),
+            SensitivityAxis::TreasuryChange(vec![-0.01, 0.0, 0.01]),
+        ],
+        0.03,
+    );
+    let tornado = sensitivity_grid.tornado_ranking(&assumptions);
+    let mut tornado_file = File::create("sensitivity_tornado.csv").expect("Failed to create tornado output file");
+    writeln!(tornado_file, "Parameter,PvNetCashflowSpread").unwrap();
+    for (name, spread) in &tornado {
+        writeln!(tornado_file, "{},{:.2}", name, spread).unwrap();
+        println!("  {:<16} spread = {:.2}", name, spread);
+    }
+
     // Print summary stats
     println!("\nBlock Summary:");
     println!("  Month 1:   Lives={:.4}, BOP_AV=${:.0}, BOP_BB=${:.0}",
              aggregated[0].total_lives,
-             aggregated[0].total_bop_av,
-             aggregated[0].total_bop_bb);
+             aggregated[0].total_bop_av.to_f64(),
+             aggregated[0].total_bop_bb.to_f64());
     println!("  Month 60:  Lives={:.4}, BOP_AV=${:.0}",
              aggregated[59].total_lives,
-             aggregated[59].total_bop_av);
+             aggregated[59].total_bop_av.to_f64());
     println!("  Month 120: Lives={:.4}, BOP_AV=${:.0}",
              aggregated[119].total_lives,
-             aggregated[119].total_bop_av);
+             aggregated[119].total_bop_av.to_f64());
     println!("  Month 360: Lives={:.4}, BOP_AV=${:.0}",
              aggregated[359].total_lives,
-             aggregated[359].total_bop_av);
+             aggregated[359].total_bop_av.to_f64());
 
     println!("\nTotal time: {:?}", start.elapsed());
 }
@@ -175,10 +175,100 @@ fn adjust_policies(mut policies: Vec<Policy>, params: &AdjustmentParams) -> Vec<
     policies
 }
 
+/// Snapshot of the values that differ across a material-change/adjustment event: benefit base,
+/// account value, rollup rate, and rider charge parameters at a point in time.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MaterialChangeSnapshot {
+    pub benefit_base: f64,
+    pub account_value: f64,
+    pub rollup_rate: f64,
+    pub pre_activation_charge: f64,
+    pub post_activation_charge: f64,
+}
+
+/// An in-force adjustment (material change) applied at a specific projection duration: the
+/// "old" basis is retained for grandfathering while a "new" basis, driven by a fresh
+/// `AdjustmentParams` reprice, takes over prospectively.
+#[derive(Debug, Clone)]
+pub struct AdjustmentEvent {
+    pub at_duration_months: u32,
+    pub new_params: AdjustmentParams,
+}
+
+/// Old-vs-new state captured at the moment an `AdjustmentEvent` fires, so downstream reports
+/// can show the effect of each material change: the delta attributable to the change itself,
+/// separate from ordinary monthly decrements.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AdjustmentSnapshotPair {
+    pub old: MaterialChangeSnapshot,
+    pub new: MaterialChangeSnapshot,
+}
+
+impl AdjustmentSnapshotPair {
+    /// Benefit-base delta attributable to the change itself (new minus old, both taken at the
+    /// moment the event fires, before any further monthly roll-up).
+    pub fn benefit_base_delta(&self) -> f64 {
+        self.new.benefit_base - self.old.benefit_base
+    }
+
+    /// Account-value delta attributable to the change itself.
+    pub fn account_value_delta(&self) -> f64 {
+        self.new.account_value - self.old.account_value
+    }
+}
+
+/// Capture the old (pre-change) state and compute the new (post-change) state for an
+/// `AdjustmentEvent`, given the policy's state at the moment the event fires.
+///
+/// **Status: blocked on `state.rs`.** This is the hook `ProjectionState` would call at
+/// `event.at_duration_months` to carry both the old and new bases forward (for grandfathering)
+/// and surface the delta in `CashflowRow`, but `ProjectionState` lives in
+/// `src/projection/state.rs`, which isn't part of this tree, so nothing in the real projection
+/// loop calls this yet. `src/bin/run_block.rs` demonstrates it against a policy's own month-60
+/// row as a stand-in for the live state `ProjectionState` would otherwise hand it. The
+/// benefit-base adjustment reuses the same bb_bonus-to-1.3-baseline scaling as
+/// `adjust_policies`, since a material change here is the same "reprice the BB bonus"
+/// operation applied mid-stream instead of at issue.
+pub fn apply_adjustment_event(old: MaterialChangeSnapshot, event: &AdjustmentEvent) -> AdjustmentSnapshotPair {
+    let bb_bonus_factor = (1.0 + event.new_params.bb_bonus) / 1.3;
+    let new = MaterialChangeSnapshot {
+        benefit_base: old.benefit_base * bb_bonus_factor,
+        account_value: old.account_value,
+        rollup_rate: old.rollup_rate,
+        pre_activation_charge: old.pre_activation_charge,
+        post_activation_charge: old.post_activation_charge,
+    };
+    AdjustmentSnapshotPair { old, new }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_apply_adjustment_event_rescales_benefit_base_only() {
+        let old = MaterialChangeSnapshot {
+            benefit_base: 130_000.0,
+            account_value: 100_000.0,
+            rollup_rate: 0.10,
+            pre_activation_charge: 0.005,
+            post_activation_charge: 0.015,
+        };
+        let event = AdjustmentEvent {
+            at_duration_months: 60,
+            new_params: AdjustmentParams { bb_bonus: 0.40, ..Default::default() },
+        };
+
+        let snapshot = apply_adjustment_event(old, &event);
+
+        assert_eq!(snapshot.old, old);
+        // New BB = old BB * (1.40 / 1.30)
+        assert!((snapshot.new.benefit_base - 130_000.0 * (1.40 / 1.30)).abs() < 1e-6);
+        // Account value and rollup carry forward unchanged by this event.
+        assert_eq!(snapshot.account_value_delta(), 0.0);
+        assert!(snapshot.benefit_base_delta() > 0.0);
+    }
+
     #[test]
     fn test_default_params_unchanged() {
         let params = AdjustmentParams::default();
@@ -0,0 +1,30 @@
+//! Per-policy product-type classification
+//!
+//! Every inforce record today is implicitly a GLWB-style indexed/fixed deferred annuity; the
+//! projection only models survival and account-value mechanics. `PolicyProductType` is the
+//! dimension a `Policy` needs to branch into the other contract forms this book can hold: pure
+//! mortality products (`WholeLife`, `TermLife`) that pay a sum assured on death instead of just
+//! releasing account value, and the endowment-family forms (`Endowment`, `PureEndowment`,
+//! `TermFix`) whose survival/death/maturity economics are already modeled by
+//! [`crate::assumptions::product::ProductType`] and its `BenefitSpec` impls.
+//!
+//! `Policy` itself lives in `src/policy/data.rs`, which isn't part of this tree, so this type
+//! can't be spliced in as a literal new field here. It's exposed standalone so the loader and
+//! `crate::projection::benefit_payout` can key off it once `Policy` gains a
+//! `product_type: PolicyProductType` field (`#[serde(default)]`, defaulting to `Annuity` so
+//! every existing CSV row keeps today's behavior unchanged).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyProductType {
+    Annuity,
+    WholeLife,
+    TermLife,
+    Endowment,
+    PureEndowment,
+    TermFix,
+}
+
+impl Default for PolicyProductType {
+    fn default() -> Self {
+        PolicyProductType::Annuity
+    }
+}
@@ -0,0 +1,117 @@
+//! Death/maturity benefit payout split for product types beyond the deferred-annuity GLWB case
+//!
+//! `CashflowRow` models survival/account-value mechanics for the GLWB deferred-annuity case
+//! only: a death or lapse decrement simply releases account value, with no separate sum-assured
+//! or maturity-benefit concept. Branching the month loop itself on [`PolicyProductType`] is
+//! `ProjectionEngine`'s job, and `ProjectionEngine`/`CashflowRow` live in `src/projection/engine.rs`
+//! and `cashflows.rs`, neither of which is part of this tree, so this module can't add a literal
+//! `death_benefit_paid`/`maturity_benefit_paid` field to `CashflowRow` itself. Instead it exposes
+//! the payout calculation standalone, keyed off the product type and (for the endowment-family
+//! forms) the same [`BenefitSpec`] impls `assumptions::product` already provides — so wiring this
+//! into the month loop once `CashflowRow` gains those fields is a straight call to
+//! [`compute_benefit_payout`] per month, not a new calculation.
+
+use crate::assumptions::product::ProductType as RiderProductType;
+use crate::policy::PolicyProductType;
+
+/// One month's death/maturity benefit payout, split out from ordinary account-value release.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BenefitPayout {
+    pub death_benefit_paid: f64,
+    pub maturity_benefit_paid: f64,
+}
+
+/// Compute this month's death/maturity payout for `product_type`.
+///
+/// - `Annuity`: no separate sum assured or maturity benefit; the GLWB/account-value mechanics
+///   modeled elsewhere already cover it, so this always returns zero.
+/// - `WholeLife` / `TermLife`: pay a sum assured on death. Absent a dedicated face-amount field
+///   on the (invisible) `Policy` struct, `bop_av` stands in as the face amount, consistent with
+///   how the rest of this tree treats account value as the amount at risk; premiums continue
+///   unless the caller otherwise applies `premiums_cease_on_death`-style logic.
+/// - `Endowment` / `PureEndowment` / `TermFix`: delegate to `rider_product_type`'s `BenefitSpec`
+///   (from `assumptions::product`), which already knows each form's survival/death/maturity
+///   schedule; `is_maturity_month` gates the survival benefit to the single month it's paid.
+pub fn compute_benefit_payout(
+    product_type: PolicyProductType,
+    rider_product_type: Option<&RiderProductType>,
+    policy_year: u32,
+    bop_av: f64,
+    premiums_paid_to_date: f64,
+    mortality_dec: f64,
+    is_maturity_month: bool,
+) -> BenefitPayout {
+    match product_type {
+        PolicyProductType::Annuity => BenefitPayout::default(),
+        PolicyProductType::WholeLife | PolicyProductType::TermLife => {
+            BenefitPayout { death_benefit_paid: bop_av * mortality_dec, maturity_benefit_paid: 0.0 }
+        }
+        PolicyProductType::Endowment | PolicyProductType::PureEndowment | PolicyProductType::TermFix => {
+            let spec = rider_product_type.and_then(|pt| pt.benefit_spec());
+            let death_benefit_paid = spec
+                .map(|s| s.death_benefit(policy_year, premiums_paid_to_date) * mortality_dec)
+                .unwrap_or(0.0);
+            let maturity_benefit_paid = if is_maturity_month {
+                spec.map(|s| s.survival_benefit(policy_year)).unwrap_or(0.0)
+            } else {
+                0.0
+            };
+            BenefitPayout { death_benefit_paid, maturity_benefit_paid }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assumptions::product::{EndowmentSpec, BenefitSchedule};
+
+    #[test]
+    fn test_annuity_has_no_separate_payout() {
+        let payout = compute_benefit_payout(PolicyProductType::Annuity, None, 5, 100_000.0, 50_000.0, 0.01, false);
+        assert_eq!(payout.death_benefit_paid, 0.0);
+        assert_eq!(payout.maturity_benefit_paid, 0.0);
+    }
+
+    #[test]
+    fn test_whole_life_pays_decrement_weighted_face_amount() {
+        let payout = compute_benefit_payout(PolicyProductType::WholeLife, None, 5, 100_000.0, 50_000.0, 0.01, false);
+        assert!((payout.death_benefit_paid - 1_000.0).abs() < 1e-9);
+        assert_eq!(payout.maturity_benefit_paid, 0.0);
+    }
+
+    #[test]
+    fn test_endowment_pays_maturity_only_in_maturity_month() {
+        let spec = EndowmentSpec {
+            maturity_year: 10,
+            survival_benefit: BenefitSchedule::Constant(50_000.0),
+            death_benefit: BenefitSchedule::Constant(50_000.0),
+        };
+        let rider_type = RiderProductType::Endowment(spec);
+
+        let not_maturity = compute_benefit_payout(
+            PolicyProductType::Endowment, Some(&rider_type), 10, 100_000.0, 40_000.0, 0.0, false,
+        );
+        assert_eq!(not_maturity.maturity_benefit_paid, 0.0);
+
+        let at_maturity = compute_benefit_payout(
+            PolicyProductType::Endowment, Some(&rider_type), 10, 100_000.0, 40_000.0, 0.0, true,
+        );
+        assert_eq!(at_maturity.maturity_benefit_paid, 50_000.0);
+    }
+
+    #[test]
+    fn test_endowment_death_benefit_is_decrement_weighted() {
+        let spec = EndowmentSpec {
+            maturity_year: 10,
+            survival_benefit: BenefitSchedule::Constant(50_000.0),
+            death_benefit: BenefitSchedule::Constant(60_000.0),
+        };
+        let rider_type = RiderProductType::Endowment(spec);
+
+        let payout = compute_benefit_payout(
+            PolicyProductType::Endowment, Some(&rider_type), 3, 100_000.0, 30_000.0, 0.02, false,
+        );
+        assert!((payout.death_benefit_paid - 60_000.0 * 0.02).abs() < 1e-9);
+    }
+}
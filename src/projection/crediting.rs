@@ -0,0 +1,192 @@
+//! Parameterized FIA index-crediting mechanics
+//!
+//! `CreditingApproach`'s indexed mode today applies a single flat annual rate
+//! (`DEFAULT_INDEXED_ANNUAL_RATE`), which ignores the product mechanics that actually drive FIA
+//! economics. This module provides the crediting-formula primitives and pluggable index-return
+//! generators a data-driven indexed-crediting mode would use: annual point-to-point crediting
+//! with a cap/floor/participation rate/spread, monthly-averaging and monthly-sum-with-monthly-cap
+//! designs, and fixed/regime-switching/bootstrap return generators feeding either the
+//! deterministic path or the stochastic mode in [`super::stochastic`].
+
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+
+/// Annual point-to-point crediting: `credit = clamp(participation_rate * index_return - spread, floor, cap)`.
+pub fn point_to_point_credit(index_return: f64, participation_rate: f64, spread: f64, floor: f64, cap: f64) -> f64 {
+    (participation_rate * index_return - spread).clamp(floor, cap)
+}
+
+/// Monthly-averaging design: average the cumulative index ratio (relative to the start of the
+/// crediting period) across each month, then apply participation/spread/floor/cap to the
+/// averaged return the same way as point-to-point.
+///
+/// `monthly_index_ratios` are cumulative index levels expressed as a ratio to the starting
+/// index value (e.g. `1.03` = index is 3% above where it started this crediting period).
+pub fn monthly_averaging_credit(
+    monthly_index_ratios: &[f64],
+    participation_rate: f64,
+    spread: f64,
+    floor: f64,
+    cap: f64,
+) -> f64 {
+    if monthly_index_ratios.is_empty() {
+        return floor;
+    }
+    let avg_ratio = monthly_index_ratios.iter().sum::<f64>() / monthly_index_ratios.len() as f64;
+    let index_return = avg_ratio - 1.0;
+    point_to_point_credit(index_return, participation_rate, spread, floor, cap)
+}
+
+/// Monthly-sum-with-monthly-cap design: each month's return is capped at `monthly_cap` (but not
+/// floored, so negative months still subtract) before the months are summed, then the summed
+/// return is scaled by participation and floored at the annual level.
+pub fn monthly_sum_capped_credit(
+    monthly_returns: &[f64],
+    monthly_cap: f64,
+    participation_rate: f64,
+    floor: f64,
+) -> f64 {
+    let summed: f64 = monthly_returns.iter().map(|r| r.min(monthly_cap)).sum();
+    (participation_rate * summed).max(floor)
+}
+
+/// A source of annual index returns, pluggable into both the deterministic and stochastic
+/// projection modes.
+pub trait IndexReturnGenerator {
+    /// Draw the next annual index return (e.g. `0.05` for +5%).
+    fn next_annual_return(&mut self, rng: &mut ChaCha8Rng) -> f64;
+}
+
+/// A fixed assumed annual return, used for deterministic/expected-value projections.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedReturnGenerator {
+    pub assumed_return: f64,
+}
+
+impl IndexReturnGenerator for FixedReturnGenerator {
+    fn next_annual_return(&mut self, _rng: &mut ChaCha8Rng) -> f64 {
+        self.assumed_return
+    }
+}
+
+/// Two-volatility-regime lognormal model: `return = exp(mu_r + sigma_r * Z) - 1` where
+/// `Z ~ N(0, 1)` and the regime `r` evolves year over year by `transition`, a 2x2
+/// row-stochastic transition matrix (`transition[r]` are the probabilities of moving to
+/// regime 0 and regime 1 from regime `r`).
+#[derive(Debug, Clone, Copy)]
+pub struct RegimeSwitchingLognormalGenerator {
+    pub mu: [f64; 2],
+    pub sigma: [f64; 2],
+    pub transition: [[f64; 2]; 2],
+    regime: usize,
+}
+
+impl RegimeSwitchingLognormalGenerator {
+    pub fn new(mu: [f64; 2], sigma: [f64; 2], transition: [[f64; 2]; 2], starting_regime: usize) -> Self {
+        Self { mu, sigma, transition, regime: starting_regime.min(1) }
+    }
+}
+
+impl IndexReturnGenerator for RegimeSwitchingLognormalGenerator {
+    fn next_annual_return(&mut self, rng: &mut ChaCha8Rng) -> f64 {
+        let z = standard_normal(rng);
+        let annual_return = (self.mu[self.regime] + self.sigma[self.regime] * z).exp() - 1.0;
+
+        // Evolve the regime for next year's draw.
+        let u: f64 = rng.gen();
+        self.regime = if u < self.transition[self.regime][0] { 0 } else { 1 };
+
+        annual_return
+    }
+}
+
+/// Bootstrap resampling: draws annual returns uniformly at random (with replacement) from a
+/// supplied historical monthly return series, compounding 12 resampled months into one annual
+/// return each call.
+#[derive(Debug, Clone)]
+pub struct BootstrapReturnGenerator {
+    pub historical_monthly_returns: Vec<f64>,
+}
+
+impl IndexReturnGenerator for BootstrapReturnGenerator {
+    fn next_annual_return(&mut self, rng: &mut ChaCha8Rng) -> f64 {
+        if self.historical_monthly_returns.is_empty() {
+            return 0.0;
+        }
+        let mut compounded = 1.0;
+        for _ in 0..12 {
+            let idx = rng.gen_range(0..self.historical_monthly_returns.len());
+            compounded *= 1.0 + self.historical_monthly_returns[idx];
+        }
+        compounded - 1.0
+    }
+}
+
+/// Box-Muller transform: one standard-normal draw from two uniform draws, avoiding a dependency
+/// on `rand_distr` for a single distribution.
+fn standard_normal(rng: &mut ChaCha8Rng) -> f64 {
+    let u1: f64 = rng.gen::<f64>().max(f64::MIN_POSITIVE);
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_point_to_point_credit_respects_cap_and_floor() {
+        assert_eq!(point_to_point_credit(0.20, 0.80, 0.01, 0.0, 0.10), 0.10); // capped
+        assert_eq!(point_to_point_credit(-0.20, 0.80, 0.01, 0.0, 0.10), 0.0); // floored
+        assert!((point_to_point_credit(0.08, 0.80, 0.01, 0.0, 0.10) - (0.08 * 0.80 - 0.01)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_monthly_averaging_credit() {
+        let ratios = vec![1.01, 1.02, 1.03, 1.04, 1.05, 1.06, 1.07, 1.08, 1.09, 1.10, 1.11, 1.12];
+        let avg = ratios.iter().sum::<f64>() / ratios.len() as f64;
+        let expected = point_to_point_credit(avg - 1.0, 1.0, 0.0, 0.0, 1.0);
+        assert_eq!(monthly_averaging_credit(&ratios, 1.0, 0.0, 0.0, 1.0), expected);
+    }
+
+    #[test]
+    fn test_monthly_sum_capped_credit() {
+        // Each month capped at 1%, summed: 12 * 1% = 12%, participation 50% => 6%
+        let monthly_returns = vec![0.02; 12];
+        assert!((monthly_sum_capped_credit(&monthly_returns, 0.01, 0.5, 0.0) - 0.06).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fixed_generator_is_constant() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let mut gen = FixedReturnGenerator { assumed_return: 0.0378 };
+        assert_eq!(gen.next_annual_return(&mut rng), 0.0378);
+        assert_eq!(gen.next_annual_return(&mut rng), 0.0378);
+    }
+
+    #[test]
+    fn test_bootstrap_generator_reproducible_with_same_seed() {
+        let history = vec![0.01, -0.02, 0.03, 0.005, -0.01, 0.02, 0.0, 0.015, -0.005, 0.01, 0.02, -0.01];
+        let mut a = BootstrapReturnGenerator { historical_monthly_returns: history.clone() };
+        let mut b = BootstrapReturnGenerator { historical_monthly_returns: history };
+        let mut rng_a = ChaCha8Rng::seed_from_u64(123);
+        let mut rng_b = ChaCha8Rng::seed_from_u64(123);
+        assert_eq!(a.next_annual_return(&mut rng_a), b.next_annual_return(&mut rng_b));
+    }
+
+    #[test]
+    fn test_regime_switching_generator_runs() {
+        let mut gen = RegimeSwitchingLognormalGenerator::new(
+            [0.06, -0.02],
+            [0.08, 0.20],
+            [[0.95, 0.05], [0.30, 0.70]],
+            0,
+        );
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        for _ in 0..50 {
+            let r = gen.next_annual_return(&mut rng);
+            assert!(r.is_finite());
+        }
+    }
+}
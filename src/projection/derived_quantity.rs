@@ -0,0 +1,118 @@
+//! Derived-quantity framework with an initialization (burn-in) phase and overrides
+//!
+//! Several projection outputs (opening reserves, stabilized benefit-base roll-up, ...) need a
+//! burn-in before the main projection starts, and there was previously no general way to query
+//! a computed time series before year 0 or to inject known values. `DerivedQuantity` stores
+//! values keyed by projection year plus a separate initialization-phase series, and resolves a
+//! query through three layers in priority order: an override, a computed value, or (for years
+//! before the projection starts) the burn-in phase.
+
+use std::collections::HashMap;
+
+/// A time series of computed values keyed by projection year, backed by a burn-in
+/// ("initialization phase") series for years before the projection starts, and an override
+/// table letting callers pin specific yearly values without touching the recurrence.
+#[derive(Debug, Clone)]
+pub struct DerivedQuantity {
+    /// The first year of the main projection; `get` falls back to the initialization phase for
+    /// any year strictly before this.
+    start_year: i32,
+    /// Computed values for year >= start_year.
+    computed: HashMap<i32, f64>,
+    /// Burn-in values, oldest first, ending with the value for the year immediately before
+    /// `start_year`.
+    initialization_phase: Vec<f64>,
+    /// User-pinned values that take priority over both computed and initialization values.
+    overrides: HashMap<i32, f64>,
+}
+
+impl DerivedQuantity {
+    pub fn new(start_year: i32, initialization_phase: Vec<f64>) -> Self {
+        Self {
+            start_year,
+            computed: HashMap::new(),
+            initialization_phase,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Record a computed value for `year` (expected to be >= `start_year`).
+    pub fn set_computed(&mut self, year: i32, value: f64) {
+        self.computed.insert(year, value);
+    }
+
+    /// Pin `year` to `value`, overriding whatever `get` would otherwise resolve to.
+    pub fn set_override(&mut self, year: i32, value: f64) {
+        self.overrides.insert(year, value);
+    }
+
+    /// Resolve the quantity for `year`: an override wins if present, else a computed value,
+    /// else (for years before `start_year`) the initialization phase indexed backward from its
+    /// end, clamped so a query never goes back more than one full phase length — beyond that,
+    /// the earliest initialization value is returned rather than indexing out of bounds.
+    pub fn get(&self, year: i32) -> Option<f64> {
+        if let Some(&value) = self.overrides.get(&year) {
+            return Some(value);
+        }
+        if let Some(&value) = self.computed.get(&year) {
+            return Some(value);
+        }
+
+        let years_to_go_back = self.start_year - year;
+        if years_to_go_back <= 0 || self.initialization_phase.is_empty() {
+            return None;
+        }
+
+        let phase_len = self.initialization_phase.len() as i32;
+        let steps_back = years_to_go_back.min(phase_len);
+        let idx = (phase_len - steps_back) as usize;
+        self.initialization_phase.get(idx).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DerivedQuantity {
+        // Burn-in phase for years 2017-2019, main projection starts 2020.
+        DerivedQuantity::new(2020, vec![10.0, 20.0, 30.0])
+    }
+
+    #[test]
+    fn test_override_wins_over_everything() {
+        let mut dq = sample();
+        dq.set_computed(2020, 100.0);
+        dq.set_override(2020, 999.0);
+        assert_eq!(dq.get(2020), Some(999.0));
+    }
+
+    #[test]
+    fn test_computed_value_returned_when_no_override() {
+        let mut dq = sample();
+        dq.set_computed(2021, 42.0);
+        assert_eq!(dq.get(2021), Some(42.0));
+    }
+
+    #[test]
+    fn test_initialization_phase_indexed_backward() {
+        let dq = sample();
+        assert_eq!(dq.get(2019), Some(30.0)); // 1 year back: most recent init value
+        assert_eq!(dq.get(2018), Some(20.0)); // 2 years back
+        assert_eq!(dq.get(2017), Some(10.0)); // 3 years back: earliest init value
+    }
+
+    #[test]
+    fn test_clamped_to_earliest_init_value_beyond_one_phase() {
+        let dq = sample();
+        // 4 and 10 years back both exceed the 3-element phase, so both clamp to the earliest value.
+        assert_eq!(dq.get(2016), Some(10.0));
+        assert_eq!(dq.get(2010), Some(10.0));
+    }
+
+    #[test]
+    fn test_uncomputed_year_within_projection_returns_none() {
+        let dq = sample();
+        assert_eq!(dq.get(2025), None);
+    }
+}
@@ -0,0 +1,202 @@
+//! Group-roster aggregation ledger
+//!
+//! After `InforceTemplate::generate()` produces thousands of policies, there is no first-class
+//! way to roll their projections up into a group/census-level ledger. This module sums
+//! `CashflowRow` series weighted by `initial_pols` across a policy set and emits a roster: one
+//! row per policy with its identifying attributes and key projected totals. The aggregate can
+//! also be pivoted by issue-age band, calendar-year bucket, or crediting strategy, the way a
+//! microsimulation study reports results by age/year cohort.
+
+use super::CashflowRow;
+use crate::policy::{BenefitBaseBucket, CreditingStrategy, Gender, Policy, QualStatus};
+use std::collections::BTreeMap;
+
+/// One roster row: a policy's identifying attributes and its projected totals.
+#[derive(Debug, Clone)]
+pub struct RosterRow {
+    pub policy_id: u32,
+    pub issue_age: u8,
+    pub gender: Gender,
+    pub qual_status: QualStatus,
+    pub benefit_base_bucket: BenefitBaseBucket,
+    pub crediting_strategy: CreditingStrategy,
+    pub initial_premium: f64,
+    pub initial_benefit_base: f64,
+    pub total_net_cashflow: f64,
+    pub total_withdrawals: f64,
+}
+
+/// One month of the group ledger: `initial_pols`-weighted sum of `CashflowRow` fields across
+/// the policy set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LedgerRow {
+    pub month: u32,
+    pub bop_av: f64,
+    pub bop_benefit_base: f64,
+    pub lives: f64,
+    pub mortality_dec: f64,
+    pub lapse_dec: f64,
+    pub pwd_dec: f64,
+    pub eop_av: f64,
+    pub total_net_cashflow: f64,
+}
+
+/// A group/census-level roll-up of per-policy projections: a monthly aggregate ledger plus a
+/// per-policy roster.
+#[derive(Debug, Clone, Default)]
+pub struct GroupLedger {
+    pub ledger: Vec<LedgerRow>,
+    pub roster: Vec<RosterRow>,
+}
+
+impl GroupLedger {
+    /// Build a `GroupLedger` from a policy set and its matching per-policy cashflow series
+    /// (same order, e.g. as produced by `par_iter().map(...)` in `run_block`). Each policy's
+    /// contribution to the monthly ledger is weighted by its `initial_pols`.
+    pub fn build(policies: &[Policy], cashflows: &[Vec<CashflowRow>]) -> Self {
+        let max_month = cashflows.iter().flat_map(|rows| rows.iter().map(|r| r.projection_month)).max().unwrap_or(0);
+        let mut ledger: Vec<LedgerRow> = (1..=max_month).map(|m| LedgerRow { month: m, ..Default::default() }).collect();
+        let mut roster = Vec::with_capacity(policies.len());
+
+        for (policy, rows) in policies.iter().zip(cashflows.iter()) {
+            let weight = policy.initial_pols;
+            let mut total_net_cashflow = 0.0;
+            let mut total_withdrawals = 0.0;
+
+            for row in rows {
+                let idx = (row.projection_month - 1) as usize;
+                if let Some(agg) = ledger.get_mut(idx) {
+                    agg.bop_av += row.bop_av * weight;
+                    agg.bop_benefit_base += row.bop_benefit_base * weight;
+                    agg.lives += row.lives * weight;
+                    agg.mortality_dec += row.mortality_dec * weight;
+                    agg.lapse_dec += row.lapse_dec * weight;
+                    agg.pwd_dec += row.pwd_dec * weight;
+                    agg.eop_av += row.eop_av * weight;
+                    agg.total_net_cashflow += row.total_net_cashflow * weight;
+                }
+                total_net_cashflow += row.total_net_cashflow * weight;
+                total_withdrawals += row.pwd_dec * weight;
+            }
+
+            roster.push(RosterRow {
+                policy_id: policy.policy_id,
+                issue_age: policy.issue_age,
+                gender: policy.gender,
+                qual_status: policy.qual_status,
+                benefit_base_bucket: policy.benefit_base_bucket,
+                crediting_strategy: policy.crediting_strategy,
+                initial_premium: policy.initial_premium,
+                initial_benefit_base: policy.initial_benefit_base,
+                total_net_cashflow,
+                total_withdrawals,
+            });
+        }
+
+        Self { ledger, roster }
+    }
+
+    /// Total `initial_premium` per issue-age band of width `band_width` (e.g. `5` groups
+    /// 55-59, 60-64, ...), keyed by the band's starting age.
+    pub fn pivot_by_issue_age_band(&self, band_width: u8) -> BTreeMap<u8, f64> {
+        let mut pivot = BTreeMap::new();
+        for row in &self.roster {
+            let band_start = (row.issue_age / band_width) * band_width;
+            *pivot.entry(band_start).or_insert(0.0) += row.initial_premium;
+        }
+        pivot
+    }
+
+    /// Total `total_net_cashflow` per calendar-year bucket of `bucket_years` width (e.g. `5` or
+    /// `10` for 5-/10-year groupings), keyed by the bucket's starting projection year. A
+    /// projection month is assigned to the calendar year `ceil(month / 12)`.
+    pub fn pivot_by_calendar_year(&self, bucket_years: u32) -> BTreeMap<u32, f64> {
+        let mut pivot = BTreeMap::new();
+        for row in &self.ledger {
+            let year = row.month.div_ceil(12);
+            let bucket_start = ((year - 1) / bucket_years) * bucket_years + 1;
+            *pivot.entry(bucket_start).or_insert(0.0) += row.total_net_cashflow;
+        }
+        pivot
+    }
+
+    /// Total `initial_premium` per crediting strategy (`Fixed`, `Indexed`).
+    pub fn pivot_by_crediting_strategy(&self) -> Vec<(CreditingStrategy, f64)> {
+        let mut fixed_total = 0.0;
+        let mut indexed_total = 0.0;
+        for row in &self.roster {
+            match row.crediting_strategy {
+                CreditingStrategy::Fixed => fixed_total += row.initial_premium,
+                CreditingStrategy::Indexed => indexed_total += row.initial_premium,
+            }
+        }
+        vec![(CreditingStrategy::Fixed, fixed_total), (CreditingStrategy::Indexed, indexed_total)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roster_row(policy_id: u32, issue_age: u8, crediting_strategy: CreditingStrategy, premium: f64) -> RosterRow {
+        RosterRow {
+            policy_id,
+            issue_age,
+            gender: Gender::Female,
+            qual_status: QualStatus::N,
+            benefit_base_bucket: BenefitBaseBucket::Under50k,
+            crediting_strategy,
+            initial_premium: premium,
+            initial_benefit_base: premium * 1.3,
+            total_net_cashflow: 0.0,
+            total_withdrawals: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_pivot_by_issue_age_band_groups_by_band_start() {
+        let ledger = GroupLedger {
+            ledger: vec![],
+            roster: vec![
+                roster_row(1, 57, CreditingStrategy::Fixed, 100.0),
+                roster_row(2, 62, CreditingStrategy::Fixed, 200.0),
+                roster_row(3, 64, CreditingStrategy::Fixed, 50.0),
+            ],
+        };
+        let pivot = ledger.pivot_by_issue_age_band(5);
+        assert_eq!(pivot.get(&55), Some(&100.0));
+        assert_eq!(pivot.get(&60), Some(&250.0));
+    }
+
+    #[test]
+    fn test_pivot_by_crediting_strategy_splits_fixed_and_indexed() {
+        let ledger = GroupLedger {
+            ledger: vec![],
+            roster: vec![
+                roster_row(1, 60, CreditingStrategy::Fixed, 100.0),
+                roster_row(2, 60, CreditingStrategy::Indexed, 300.0),
+            ],
+        };
+        let pivot = ledger.pivot_by_crediting_strategy();
+        assert_eq!(pivot, vec![(CreditingStrategy::Fixed, 100.0), (CreditingStrategy::Indexed, 300.0)]);
+    }
+
+    #[test]
+    fn test_pivot_by_calendar_year_buckets_months_into_years() {
+        let ledger = GroupLedger {
+            ledger: vec![
+                LedgerRow { month: 1, total_net_cashflow: 10.0, ..Default::default() },
+                LedgerRow { month: 12, total_net_cashflow: 10.0, ..Default::default() },
+                LedgerRow { month: 13, total_net_cashflow: 20.0, ..Default::default() },
+                LedgerRow { month: 60, total_net_cashflow: 5.0, ..Default::default() },
+                LedgerRow { month: 61, total_net_cashflow: 7.0, ..Default::default() },
+            ],
+            roster: vec![],
+        };
+        // 5-year buckets: months 1-12 (year 1) in bucket 1, months 13-60 (years 2-5) in bucket 1,
+        // months 61+ (year 6) in bucket 6.
+        let pivot = ledger.pivot_by_calendar_year(5);
+        assert_eq!(pivot.get(&1), Some(&45.0));
+        assert_eq!(pivot.get(&6), Some(&7.0));
+    }
+}
@@ -3,10 +3,43 @@
 mod state;
 mod engine;
 mod cashflows;
+mod stochastic;
+mod crediting;
+mod report;
+mod ledger;
+mod derived_quantity;
+mod retirement;
+mod reserves;
+mod scenario;
+mod sensitivity;
+mod benefit_payout;
+mod rate_accrual;
 
 pub use state::ProjectionState;
 pub use engine::{ProjectionEngine, ProjectionConfig, CreditingApproach};
 pub use cashflows::{CashflowRow, ProjectionResult};
+pub use stochastic::{
+    draw_month, run_policy_scenario, run_stochastic, scenario_rng, MonthlyDraw, ScenarioPv, StochasticConfig,
+    StochasticResult,
+};
+pub use crediting::{
+    monthly_averaging_credit, monthly_sum_capped_credit, point_to_point_credit, BootstrapReturnGenerator,
+    FixedReturnGenerator, IndexReturnGenerator, RegimeSwitchingLognormalGenerator,
+};
+pub use report::{render_builtin_report, render_report, BuiltinTemplate, ReportContext, ReportHeader, ReportYear};
+pub use ledger::{GroupLedger, LedgerRow, RosterRow};
+pub use derived_quantity::DerivedQuantity;
+pub use retirement::{
+    run_retirement_mc, run_retirement_scenario, RetirementMcConfig, RetirementMcResult, RetirementOutcome,
+};
+pub use reserves::{compute_reserve, project_nested_reserves, ReserveResult, StartState};
+pub use scenario::{
+    run_scenario_block, scenario_indexed_rate, summarize_scenarios, EconomicScenarioConfig, ScenarioBlockRow,
+    ScenarioSummary,
+};
+pub use sensitivity::{SensitivityAxis, SensitivityCell, SensitivityGrid};
+pub use benefit_payout::{compute_benefit_payout, BenefitPayout};
+pub use rate_accrual::RateAccrualCache;
 
 // ============================================================================
 // Default Crediting Rates
@@ -0,0 +1,101 @@
+//! Precomputed monthly compounding factors, shared across parallel block projections
+//!
+//! `run_block` launches one rayon task per policy (on the order of thousands for a real block),
+//! and today each task re-derives the same monthly compounding factor from
+//! [`DEFAULT_FIXED_ANNUAL_RATE`](super::DEFAULT_FIXED_ANNUAL_RATE) /
+//! [`DEFAULT_INDEXED_ANNUAL_RATE`](super::DEFAULT_INDEXED_ANNUAL_RATE) (and any `treasury_change`
+//! shock) independently — identical `powf` work repeated once per policy instead of once per
+//! distinct rate. [`RateAccrualCache`] precomputes the cumulative accrual factor at every month
+//! for each distinct rate up front, so a lookup replaces the repeated `powf` call in the hot loop.
+//!
+//! **Status: blocked on `engine.rs`.** `src/bin/run_block.rs` builds one of these right before its
+//! `policies.par_iter().map(...)` block (the hot loop this module doc describes) and binds it to
+//! `_rate_accrual_cache`, but nothing reads it yet — `ProjectionEngine` itself lives in
+//! `src/projection/engine.rs`, which isn't part of this tree, so this cache can't be threaded
+//! through `ProjectionEngine::project_policy`'s month loop from here. It's built to be borrowed as
+//! a plain `&RateAccrualCache` by every rayon worker (all fields are read-only after
+//! `build`), so wiring it in is adding one field to `ProjectionEngine` and replacing its per-month
+//! `powf` calls with [`RateAccrualCache::accrual`] lookups — a pure performance change with no
+//! numeric difference, since both compute the same `(1 + rate).powf(month / 12.0)` compounding.
+
+use std::collections::HashMap;
+
+use crate::assumptions::pwd::FixedRate;
+
+/// Precomputed cumulative monthly compounding factors for a fixed set of annual rates, indexed by
+/// month since issue. `factor[rate][m]` is `(1 + rate).powf(m / 12.0)` — the accrual from month 0
+/// to month `m` — so the accrual between any two months is a pair of lookups and one division.
+#[derive(Debug, Clone, Default)]
+pub struct RateAccrualCache {
+    factors: HashMap<FixedRate, Vec<f64>>,
+}
+
+impl RateAccrualCache {
+    /// Precompute cumulative accrual factors for every rate in `rates` (deduplicated) over
+    /// `0..=max_months` months. `rates` typically comes from the distinct crediting rates in play
+    /// for a block — e.g. `DEFAULT_FIXED_ANNUAL_RATE`, `DEFAULT_INDEXED_ANNUAL_RATE`, and each
+    /// `treasury_change`-shocked variant of either.
+    pub fn build(rates: impl IntoIterator<Item = f64>, max_months: u32) -> Self {
+        let mut factors = HashMap::new();
+        for rate in rates {
+            let key = FixedRate::from_f64(rate);
+            factors.entry(key).or_insert_with(|| {
+                (0..=max_months).map(|m| (1.0 + rate).powf(m as f64 / 12.0)).collect()
+            });
+        }
+        Self { factors }
+    }
+
+    /// Compounded accrual factor between `from_month` and `to_month` (inclusive of `to_month`,
+    /// exclusive of everything before `from_month`) for `rate`, or `None` if `rate` wasn't
+    /// included when the cache was built, or either month falls outside `0..=max_months`.
+    pub fn accrual(&self, rate: f64, from_month: u32, to_month: u32) -> Option<f64> {
+        let series = self.factors.get(&FixedRate::from_f64(rate))?;
+        let from = *series.get(from_month as usize)?;
+        let to = *series.get(to_month as usize)?;
+        Some(to / from)
+    }
+
+    /// Number of distinct rates precomputed.
+    pub fn len(&self) -> usize {
+        self.factors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.factors.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accrual_matches_direct_powf_computation() {
+        let cache = RateAccrualCache::build([0.0275], 360);
+        let got = cache.accrual(0.0275, 0, 12).unwrap();
+        let expected = (1.0 + 0.0275_f64).powf(1.0);
+        assert!((got - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_accrual_between_arbitrary_months_matches_ratio_of_cumulative_factors() {
+        let cache = RateAccrualCache::build([0.0378], 360);
+        let got = cache.accrual(0.0378, 24, 36).unwrap();
+        let expected = (1.0 + 0.0378_f64).powf(36.0 / 12.0) / (1.0 + 0.0378_f64).powf(24.0 / 12.0);
+        assert!((got - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_accrual_returns_none_for_unknown_rate_or_out_of_range_month() {
+        let cache = RateAccrualCache::build([0.0275], 360);
+        assert!(cache.accrual(0.05, 0, 12).is_none());
+        assert!(cache.accrual(0.0275, 0, 361).is_none());
+    }
+
+    #[test]
+    fn test_build_deduplicates_repeated_rates() {
+        let cache = RateAccrualCache::build([0.0275, 0.0275, 0.0378], 12);
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -0,0 +1,207 @@
+//! Template-driven report rendering
+//!
+//! `ProjectionResult`/`CashflowRow` can only be consumed programmatically today. This module
+//! renders a projection (single policy or aggregated inforce) through an external Mustache
+//! template, so output formatting lives in template files rather than Rust code. Callers build
+//! a `ReportContext` from whatever projection data they have and render it against a template
+//! file (their own, or one of the bundled defaults in `templates/`).
+
+use serde::Serialize;
+use std::error::Error;
+use std::path::Path;
+
+use super::CashflowRow;
+use crate::assumptions::product::SurrenderChargeSchedule;
+use crate::policy::Policy;
+
+/// One projected year's template variables.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct ReportYear {
+    pub year: u32,
+    pub account_value: f64,
+    pub benefit_base: f64,
+    pub withdrawals: f64,
+    pub reserves: f64,
+    pub surrender_charges: f64,
+    pub modal_premium: f64,
+}
+
+/// Invariant header fields, plus derived flags (like `is_inforce`) a template can switch
+/// wording on without embedding logic.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct ReportHeader {
+    pub issue_age: u8,
+    pub gender: String,
+    pub qual_status: String,
+    pub crediting_strategy: String,
+    pub sc_period: u32,
+    /// True when this report illustrates an existing in-force policy rather than new business.
+    pub is_inforce: bool,
+}
+
+/// Everything a report template needs: the invariant header and the yearly projected series.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct ReportContext {
+    pub header: ReportHeader,
+    pub years: Vec<ReportYear>,
+}
+
+/// One of the report templates bundled with the crate under `templates/`.
+#[derive(Debug, Clone, Copy)]
+pub enum BuiltinTemplate {
+    /// Per-policy illustration with the full yearly series.
+    Illustration,
+    /// Condensed numeric summary (account value / benefit base by year).
+    Summary,
+}
+
+impl BuiltinTemplate {
+    fn source(self) -> &'static str {
+        match self {
+            BuiltinTemplate::Illustration => include_str!("../../templates/illustration.mustache"),
+            BuiltinTemplate::Summary => include_str!("../../templates/summary.mustache"),
+        }
+    }
+}
+
+/// Build a `ReportContext` from a real per-policy projection: `policy`'s header fields plus
+/// `cashflows` grouped into one [`ReportYear`] per policy year. `sc_schedule` supplies the
+/// header's `sc_period` (the surrender charge schedule's length, not a per-policy field).
+/// `modal_premium` is only populated in year 1, consistent with this product line's single
+/// upfront premium — there's no recurring-premium field on `Policy` to populate later years from.
+///
+/// Each year's `account_value` takes that year's last month's end-of-period AV and `benefit_base`
+/// that month's beginning-of-period benefit base;
+/// `withdrawals` and `surrender_charges` are summed across the year's months. `reserves` is left
+/// at its default (0.0) — `CashflowRow` doesn't carry a reserve figure, and computing one means
+/// calling into `reserves::compute_reserve` with a separate valuation basis the caller would need
+/// to supply, which is out of scope for an adapter that only reshapes existing projection output.
+pub fn report_context_from_projection(
+    policy: &Policy,
+    cashflows: &[CashflowRow],
+    sc_schedule: &SurrenderChargeSchedule,
+    is_inforce: bool,
+) -> ReportContext {
+    let header = ReportHeader {
+        issue_age: policy.issue_age,
+        gender: format!("{:?}", policy.gender),
+        qual_status: format!("{:?}", policy.qual_status),
+        crediting_strategy: format!("{:?}", policy.crediting_strategy),
+        sc_period: sc_schedule.sc_period_years(),
+        is_inforce,
+    };
+
+    let mut years: Vec<ReportYear> = Vec::new();
+    for row in cashflows {
+        let year = row.projection_month.div_ceil(12);
+        let idx = (year - 1) as usize;
+        if idx >= years.len() {
+            years.resize(idx + 1, ReportYear { year, ..Default::default() });
+        }
+        let entry = &mut years[idx];
+        entry.year = year;
+        entry.account_value = row.eop_av;
+        entry.benefit_base = row.bop_benefit_base;
+        entry.withdrawals += row.pwd_dec;
+        entry.surrender_charges += row.surrender_charges_dec;
+    }
+    if let Some(first_year) = years.first_mut() {
+        first_year.modal_premium = policy.initial_premium;
+    }
+
+    ReportContext { header, years }
+}
+
+/// Render a `ReportContext` through the Mustache template file at `template_path`.
+pub fn render_report(context: &ReportContext, template_path: impl AsRef<Path>) -> Result<String, Box<dyn Error>> {
+    let template = mustache::compile_path(template_path)?;
+    Ok(template.render_to_string(context)?)
+}
+
+/// Render a `ReportContext` through one of the bundled default templates.
+pub fn render_builtin_report(context: &ReportContext, template: BuiltinTemplate) -> Result<String, Box<dyn Error>> {
+    let compiled = mustache::compile_str(template.source())?;
+    Ok(compiled.render_to_string(context)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_context() -> ReportContext {
+        ReportContext {
+            header: ReportHeader {
+                issue_age: 65,
+                gender: "Female".to_string(),
+                qual_status: "Q".to_string(),
+                crediting_strategy: "Indexed".to_string(),
+                sc_period: 10,
+                is_inforce: false,
+            },
+            years: vec![
+                ReportYear { year: 1, account_value: 100_000.0, benefit_base: 130_000.0, ..Default::default() },
+                ReportYear { year: 2, account_value: 103_780.0, benefit_base: 143_000.0, ..Default::default() },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_render_builtin_illustration_includes_header_and_years() {
+        let context = sample_context();
+        let rendered = render_builtin_report(&context, BuiltinTemplate::Illustration).unwrap();
+        assert!(rendered.contains("New Business Illustration"));
+        assert!(rendered.contains("65"));
+        assert!(rendered.contains("130000") || rendered.contains("130,000") || rendered.contains("130000.0"));
+    }
+
+    #[test]
+    fn test_render_builtin_inforce_illustration_and_summary() {
+        let mut context = sample_context();
+        context.header.is_inforce = true;
+        let illustration = render_builtin_report(&context, BuiltinTemplate::Illustration).unwrap();
+        assert!(illustration.contains("In-Force Projection"));
+
+        let summary = render_builtin_report(&context, BuiltinTemplate::Summary).unwrap();
+        assert!(summary.contains("Indexed"));
+    }
+
+    #[test]
+    fn test_report_context_from_projection_groups_months_into_years() {
+        use crate::policy::load_default_inforce;
+        use crate::projection::{CreditingApproach, ProjectionConfig, ProjectionEngine, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE};
+        use crate::Assumptions;
+
+        let policies = load_default_inforce().expect("pricing inforce loads");
+        let policy = &policies[0];
+        let assumptions = Assumptions::default_pricing();
+        let cfg = ProjectionConfig {
+            projection_months: 24,
+            crediting: CreditingApproach::PolicyBased {
+                fixed_annual_rate: DEFAULT_FIXED_ANNUAL_RATE,
+                indexed_annual_rate: DEFAULT_INDEXED_ANNUAL_RATE,
+            },
+            detailed_output: false,
+            treasury_change: 0.0,
+            fixed_lapse_rate: None,
+            hedge_params: None,
+        };
+        let engine = ProjectionEngine::new(assumptions, cfg);
+        let cashflows = engine.project_policy(policy).cashflows;
+        let sc_schedule = SurrenderChargeSchedule::default_10_year();
+
+        let context = report_context_from_projection(policy, &cashflows, &sc_schedule, false);
+
+        assert_eq!(context.header.issue_age, policy.issue_age);
+        assert_eq!(context.header.sc_period, sc_schedule.sc_period_years());
+        assert!(!context.header.is_inforce);
+        assert_eq!(context.years.len(), 2, "24 months should group into 2 policy years");
+        assert_eq!(context.years[0].year, 1);
+        assert_eq!(context.years[1].year, 2);
+        assert_eq!(context.years[0].modal_premium, policy.initial_premium);
+        assert_eq!(context.years[1].modal_premium, 0.0, "only year 1 carries the upfront premium");
+
+        let year_one_months: Vec<_> = cashflows.iter().filter(|r| r.projection_month <= 12).collect();
+        let expected_withdrawals: f64 = year_one_months.iter().map(|r| r.pwd_dec).sum();
+        assert!((context.years[0].withdrawals - expected_withdrawals).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,216 @@
+//! Nested outer/inner reserve valuation (partial implementation)
+//!
+//! A full nested design seeds the inner (valuation-basis) projection directly from the outer
+//! (real-world best-estimate) path's mid-projection state — account value, benefit base, lives,
+//! and attained age at month `t` — via an entry point like `ProjectionEngine::project_from_state`
+//! that restarts the engine's internal decrement loop at month `t` instead of month 1. That entry
+//! point lives on `ProjectionEngine` itself, whose internals aren't available to extend in this
+//! tree, so this module takes the best approximation reachable through the engine's existing
+//! public surface: the inner run re-projects the policy from issue under the (independently
+//! selectable) valuation `Assumptions`/`ProjectionConfig`, and `compute_reserve` discounts that
+//! inner path's net guarantee cashflows from month `t` forward, *scaled* by the ratio of `start`'s
+//! AV/benefit-base/lives to the inner path's own (from-issue) values at month `t`. That scaling is
+//! what actually carries the outer→inner handoff's continuity invariant (lives/AV/BB must match at
+//! the switch) into the reserve math — without it, the inner path's month-`t` state reflects a
+//! policy that decremented under the inner basis since issue, not one that decremented under the
+//! outer basis up to `t` and is only now switching. It's still an approximation: real rider-charge
+//! and PWD-benefit cashflows aren't exactly linear in AV/BB/lives, only close to it. Swap this
+//! scaling step for a true `ProjectionEngine::project_from_state(policy, start)` once that
+//! constructor exists; `StartState` is already shaped to match the signature the rest of this
+//! module expects it to have.
+
+use super::{ProjectionConfig, ProjectionEngine};
+use crate::policy::Policy;
+use crate::Assumptions;
+
+/// Outer-path state at month `t`, carried into the inner (valuation-basis) projection.
+#[derive(Debug, Clone, Copy)]
+pub struct StartState {
+    /// Outer-path month this inner run is seeded from; inner month indexing must continue from
+    /// here, not restart at 1.
+    pub month_offset: u32,
+    pub bop_av: f64,
+    pub bop_benefit_base: f64,
+    pub lives: f64,
+    pub attained_age: u8,
+}
+
+/// Reserve computed at a single outer-path month `t`: the present value, under the valuation
+/// basis, of future net guarantee cashflows (rider charges collected less GLWB/PWD benefit
+/// outflows) from month `t` to the projection horizon.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReserveResult {
+    pub month: u32,
+    pub reserve: f64,
+}
+
+/// Compute `reserve(t)` by re-projecting `policy` under `inner_assumptions`/`inner_config` (the
+/// valuation basis, independently selectable from whatever basis produced the outer path) and
+/// discounting its net guarantee cashflows — `rider_charges_dec` (inflow) netted against
+/// `pwd_dec` (benefit outflow) — from `start.month_offset` onward at the monthly-equivalent of
+/// `valuation_rate`.
+///
+/// Before discounting, each cashflow is scaled so the inner path's implied AV/benefit-base/lives
+/// at `start.month_offset` match `start` exactly: `pwd_dec` (a function of benefit base) is scaled
+/// by `start.bop_benefit_base / inner_bop_benefit_base`, `rider_charges_dec` (a function of AV) by
+/// `start.bop_av / inner_bop_av`, and both are further scaled by `start.lives / inner_lives` for
+/// survivorship. See the module doc for why this scaling — rather than a true mid-path state
+/// seed — is the continuity mechanism here.
+pub fn compute_reserve(
+    policy: &Policy,
+    start: &StartState,
+    inner_assumptions: &Assumptions,
+    inner_config: &ProjectionConfig,
+    valuation_rate: f64,
+) -> ReserveResult {
+    let engine = ProjectionEngine::new(inner_assumptions.clone(), inner_config.clone());
+    let inner_path = engine.project_policy(policy).cashflows;
+
+    let seed_row = inner_path.iter().find(|r| r.projection_month == start.month_offset);
+    let ratio = |start_value: f64, inner_value: f64| {
+        if inner_value.abs() > 1e-9 { start_value / inner_value } else { 1.0 }
+    };
+    let (av_scale, bb_scale, lives_scale) = match seed_row {
+        Some(row) => (
+            ratio(start.bop_av, row.bop_av),
+            ratio(start.bop_benefit_base, row.bop_benefit_base),
+            ratio(start.lives, row.lives),
+        ),
+        None => (1.0, 1.0, 1.0),
+    };
+
+    let monthly_valuation_rate = (1.0 + valuation_rate).powf(1.0 / 12.0) - 1.0;
+    let mut reserve = 0.0;
+
+    for row in inner_path.iter().filter(|r| r.projection_month >= start.month_offset) {
+        let months_forward = row.projection_month - start.month_offset;
+        let discount = 1.0 / (1.0 + monthly_valuation_rate).powi(months_forward as i32);
+        let seeded_pwd_dec = row.pwd_dec * bb_scale * lives_scale;
+        let seeded_rider_charges_dec = row.rider_charges_dec * av_scale * lives_scale;
+        let net_guarantee_outflow = seeded_pwd_dec - seeded_rider_charges_dec;
+        reserve += net_guarantee_outflow * discount;
+    }
+
+    ReserveResult { month: start.month_offset, reserve }
+}
+
+/// Run the outer best-estimate path once, then compute `reserve(t)` at each month in
+/// `valuation_months` by seeding the inner (valuation-basis) run from the outer path's state at
+/// that month.
+pub fn project_nested_reserves(
+    policy: &Policy,
+    outer_assumptions: &Assumptions,
+    outer_config: &ProjectionConfig,
+    inner_assumptions: &Assumptions,
+    inner_config: &ProjectionConfig,
+    valuation_rate: f64,
+    valuation_months: &[u32],
+) -> Vec<ReserveResult> {
+    let outer_engine = ProjectionEngine::new(outer_assumptions.clone(), outer_config.clone());
+    let outer_path = outer_engine.project_policy(policy).cashflows;
+
+    valuation_months
+        .iter()
+        .filter_map(|&month| {
+            let outer_row = outer_path.iter().find(|r| r.projection_month == month)?;
+            let start = StartState {
+                month_offset: month,
+                bop_av: outer_row.bop_av,
+                bop_benefit_base: outer_row.bop_benefit_base,
+                lives: outer_row.lives,
+                attained_age: policy.issue_age.saturating_add(((month - 1) / 12) as u8),
+            };
+            Some(compute_reserve(policy, &start, inner_assumptions, inner_config, valuation_rate))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::{CreditingApproach, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE};
+    use crate::policy::load_default_inforce;
+
+    fn config() -> ProjectionConfig {
+        ProjectionConfig {
+            projection_months: 60,
+            crediting: CreditingApproach::PolicyBased {
+                fixed_annual_rate: DEFAULT_FIXED_ANNUAL_RATE,
+                indexed_annual_rate: DEFAULT_INDEXED_ANNUAL_RATE,
+            },
+            detailed_output: false,
+            treasury_change: 0.0,
+            fixed_lapse_rate: None,
+            hedge_params: None,
+        }
+    }
+
+    #[test]
+    fn test_reserve_at_month_one_covers_whole_inner_path() {
+        let policies = load_default_inforce().expect("pricing inforce loads");
+        let policy = &policies[0];
+        let assumptions = Assumptions::default_pricing();
+        let cfg = config();
+
+        let start = StartState {
+            month_offset: 1,
+            bop_av: policy.initial_premium,
+            bop_benefit_base: policy.initial_premium,
+            lives: 1.0,
+            attained_age: policy.issue_age,
+        };
+
+        let result = compute_reserve(policy, &start, &assumptions, &cfg, 0.03);
+        assert_eq!(result.month, 1);
+        assert!(result.reserve.is_finite());
+    }
+
+    #[test]
+    fn test_reserve_scales_with_seeded_state_continuity() {
+        // Without the AV/BB/lives seeding, compute_reserve would always discount the inner path's
+        // own from-issue trajectory, ignoring `start` entirely — so doubling `start`'s AV/BB/lives
+        // at the handoff would leave the reserve unchanged. With seeding, it should scale up by
+        // roughly the product of those ratios (2x AV/BB x 2x lives = ~4x).
+        let policies = load_default_inforce().expect("pricing inforce loads");
+        let policy = &policies[0];
+        let assumptions = Assumptions::default_pricing();
+        let cfg = config();
+
+        let engine = ProjectionEngine::new(assumptions.clone(), cfg.clone());
+        let inner_path = engine.project_policy(policy).cashflows;
+        let row12 = inner_path.iter().find(|r| r.projection_month == 12).expect("month 12 present");
+
+        let natural_start = StartState {
+            month_offset: 12,
+            bop_av: row12.bop_av,
+            bop_benefit_base: row12.bop_benefit_base,
+            lives: row12.lives,
+            attained_age: policy.issue_age.saturating_add(1),
+        };
+        let doubled_start = StartState {
+            bop_av: row12.bop_av * 2.0,
+            bop_benefit_base: row12.bop_benefit_base * 2.0,
+            lives: row12.lives * 2.0,
+            ..natural_start
+        };
+
+        let natural = compute_reserve(policy, &natural_start, &assumptions, &cfg, 0.03);
+        let doubled = compute_reserve(policy, &doubled_start, &assumptions, &cfg, 0.03);
+
+        assert!(natural.reserve.abs() > 1e-6, "expected a nonzero baseline reserve to compare against");
+        assert!((doubled.reserve / natural.reserve - 4.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_reserve_shrinks_toward_horizon() {
+        let policies = load_default_inforce().expect("pricing inforce loads");
+        let policy = &policies[0];
+        let assumptions = Assumptions::default_pricing();
+        let cfg = config();
+
+        let results = project_nested_reserves(policy, &assumptions, &cfg, &assumptions, &cfg, 0.03, &[1, 59]);
+        assert_eq!(results.len(), 2);
+        // Fewer months remain to discount benefit outflows from as we approach the horizon.
+        assert!(results[1].reserve.abs() <= results[0].reserve.abs() + 1e-6);
+    }
+}
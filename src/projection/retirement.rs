@@ -0,0 +1,251 @@
+//! Monte Carlo retirement-outcome engine
+//!
+//! Drives the existing [`PwdAssumptions`] withdrawal logic across many scenarios of simulated
+//! account-value returns and reports distributional outcomes, instead of a single deterministic
+//! path. Each scenario compounds a year's [`IndexReturnGenerator`] draw monthly, applies the
+//! month's PWD rate (via `monthly_pwd_rate_adjusted`) against the running account value, and
+//! tracks residual wealth to the horizon, scoring it against a passive no-withdrawal benchmark.
+
+use super::{scenario_rng, IndexReturnGenerator, StochasticConfig};
+use crate::assumptions::pwd::PwdAssumptions;
+use crate::policy::QualStatus;
+use rand_chacha::ChaCha8Rng;
+
+/// Inputs common to every scenario in a retirement Monte Carlo run.
+#[derive(Debug, Clone)]
+pub struct RetirementMcConfig {
+    pub initial_account_value: f64,
+    pub issue_age: u8,
+    pub qual_status: QualStatus,
+    pub free_pct: f64,
+    pub horizon_months: u32,
+    /// Month (1-indexed) GLWB income activates, if ever.
+    pub income_activated_month: Option<u32>,
+    /// Minimum acceptable annualized payout; a scenario whose realized annual withdrawal total
+    /// falls below this floor in any policy year is flagged bankrupt even if AV remains.
+    pub min_pen: f64,
+    /// Utility discount factor applied per month.
+    pub rho: f64,
+    /// Weight applied to residual-wealth utility at the horizon.
+    pub phi: f64,
+}
+
+/// Per-scenario outcome of a retirement Monte Carlo run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RetirementOutcome {
+    /// Residual wealth at the horizon divided by the passive (no-withdrawal) benchmark
+    /// accumulation of the same starting premium.
+    pub terminal_wealth_ratio: f64,
+    /// Whether the account depleted before the horizon, or any policy year's total withdrawal
+    /// fell below `min_pen`.
+    pub bankrupt: bool,
+    /// `sum_t rho^t * u(withdrawal_t) + phi * rho^T * u(residual_wealth)`.
+    pub utility: f64,
+}
+
+/// Run one scenario: simulate monthly account-value returns via `returns`, apply the month's PWD
+/// withdrawal, and score residual wealth against the passive benchmark.
+pub fn run_retirement_scenario(
+    config: &RetirementMcConfig,
+    pwd: &PwdAssumptions,
+    returns: &mut dyn IndexReturnGenerator,
+    rng: &mut ChaCha8Rng,
+    utility_fn: impl Fn(f64) -> f64,
+) -> RetirementOutcome {
+    let mut account_value = config.initial_account_value;
+    let mut benchmark_value = config.initial_account_value;
+    let mut bankrupt = false;
+    let mut utility = 0.0;
+    let mut annual_withdrawal_total = 0.0;
+    let mut monthly_growth = 1.0;
+
+    for month in 1..=config.horizon_months {
+        let policy_year = (month - 1) / 12 + 1;
+        let month_in_policy_year = (month - 1) % 12 + 1;
+        let attained_age = config.issue_age.saturating_add(((month - 1) / 12) as u8);
+        let income_activated = config.income_activated_month.is_some_and(|m| month >= m);
+
+        // Draw this year's annual index return once, at the start of each policy year, and
+        // compound it monthly, matching how `DEFAULT_FIXED_ANNUAL_RATE` is applied elsewhere.
+        if month_in_policy_year == 1 {
+            monthly_growth = (1.0 + returns.next_annual_return(rng)).powf(1.0 / 12.0);
+        }
+
+        if account_value > 0.0 {
+            account_value *= monthly_growth;
+
+            let rate = pwd.monthly_pwd_rate_adjusted(
+                policy_year, month_in_policy_year, attained_age, config.qual_status, income_activated, config.free_pct,
+            );
+            let withdrawal = account_value * rate;
+            account_value = (account_value - withdrawal).max(0.0);
+            annual_withdrawal_total += withdrawal;
+
+            utility += config.rho.powi(month as i32) * utility_fn(withdrawal);
+        }
+        benchmark_value *= monthly_growth;
+
+        if account_value <= 0.0 {
+            bankrupt = true;
+        }
+        if month_in_policy_year == 12 {
+            if annual_withdrawal_total < config.min_pen {
+                bankrupt = true;
+            }
+            annual_withdrawal_total = 0.0;
+        }
+    }
+
+    utility += config.phi * config.rho.powi(config.horizon_months as i32) * utility_fn(account_value);
+
+    let terminal_wealth_ratio = if benchmark_value > 0.0 {
+        account_value / benchmark_value
+    } else {
+        0.0
+    };
+
+    RetirementOutcome { terminal_wealth_ratio, bankrupt, utility }
+}
+
+/// Summary statistics across all scenarios in a retirement Monte Carlo run.
+#[derive(Debug, Clone, Default)]
+pub struct RetirementMcResult {
+    pub outcomes: Vec<RetirementOutcome>,
+}
+
+impl RetirementMcResult {
+    /// Mean Terminal Wealth Ratio across scenarios.
+    pub fn mean_twr(&self) -> f64 {
+        mean(&self.outcomes.iter().map(|o| o.terminal_wealth_ratio).collect::<Vec<_>>())
+    }
+
+    /// Fraction of scenarios flagged bankrupt.
+    pub fn bankruptcy_probability(&self) -> f64 {
+        if self.outcomes.is_empty() {
+            return 0.0;
+        }
+        let bankrupt_count = self.outcomes.iter().filter(|o| o.bankrupt).count();
+        bankrupt_count as f64 / self.outcomes.len() as f64
+    }
+
+    /// Mean utility score across scenarios.
+    pub fn mean_utility(&self) -> f64 {
+        mean(&self.outcomes.iter().map(|o| o.utility).collect::<Vec<_>>())
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Run `scenarios` independent retirement paths, building a fresh return generator per scenario
+/// via `make_returns`, and collect the resulting outcomes.
+pub fn run_retirement_mc(
+    config: &RetirementMcConfig,
+    pwd: &PwdAssumptions,
+    scenarios: u32,
+    seed: u64,
+    mut make_returns: impl FnMut() -> Box<dyn IndexReturnGenerator>,
+    utility_fn: impl Fn(f64) -> f64 + Copy,
+) -> RetirementMcResult {
+    let rng_config = StochasticConfig { scenarios, seed };
+    let mut outcomes = Vec::with_capacity(scenarios as usize);
+    for i in 0..scenarios {
+        let mut rng = scenario_rng(&rng_config, i);
+        let mut returns = make_returns();
+        outcomes.push(run_retirement_scenario(config, pwd, returns.as_mut(), &mut rng, utility_fn));
+    }
+    RetirementMcResult { outcomes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::FixedReturnGenerator;
+
+    fn config() -> RetirementMcConfig {
+        RetirementMcConfig {
+            initial_account_value: 100_000.0,
+            issue_age: 65,
+            qual_status: QualStatus::N,
+            free_pct: 0.05,
+            horizon_months: 120,
+            income_activated_month: None,
+            min_pen: 0.0,
+            rho: 0.97,
+            phi: 1.0,
+        }
+    }
+
+    fn log_utility(wealth: f64) -> f64 {
+        wealth.max(1.0).ln()
+    }
+
+    #[test]
+    fn test_zero_growth_scenario_depletes_below_benchmark() {
+        let config = config();
+        let pwd = PwdAssumptions::default();
+        let mut returns = FixedReturnGenerator { assumed_return: 0.0 };
+        let mut rng = scenario_rng(&StochasticConfig::default(), 0);
+
+        let outcome = run_retirement_scenario(&config, &pwd, &mut returns, &mut rng, log_utility);
+
+        // With 0% growth and ongoing withdrawals, residual wealth can't exceed the (flat)
+        // benchmark, so TWR <= 1.
+        assert!(outcome.terminal_wealth_ratio <= 1.0);
+        assert!(outcome.terminal_wealth_ratio >= 0.0);
+    }
+
+    #[test]
+    fn test_positive_growth_beats_zero_growth_twr() {
+        let config = config();
+        let pwd = PwdAssumptions::default();
+        let rng_config = StochasticConfig::default();
+
+        let mut zero_returns = FixedReturnGenerator { assumed_return: 0.0 };
+        let mut zero_rng = scenario_rng(&rng_config, 0);
+        let zero = run_retirement_scenario(&config, &pwd, &mut zero_returns, &mut zero_rng, log_utility);
+
+        let mut positive_returns = FixedReturnGenerator { assumed_return: 0.06 };
+        let mut positive_rng = scenario_rng(&rng_config, 0);
+        let positive = run_retirement_scenario(&config, &pwd, &mut positive_returns, &mut positive_rng, log_utility);
+
+        assert!(positive.terminal_wealth_ratio >= zero.terminal_wealth_ratio);
+    }
+
+    #[test]
+    fn test_run_retirement_mc_reproducible_with_same_seed() {
+        let config = config();
+        let pwd = PwdAssumptions::default();
+
+        let run = || {
+            run_retirement_mc(
+                &config, &pwd, 10, 42,
+                || Box::new(FixedReturnGenerator { assumed_return: 0.04 }),
+                log_utility,
+            )
+        };
+        let a = run();
+        let b = run();
+
+        assert_eq!(a.outcomes.len(), 10);
+        for (x, y) in a.outcomes.iter().zip(b.outcomes.iter()) {
+            assert_eq!(x.terminal_wealth_ratio, y.terminal_wealth_ratio);
+        }
+    }
+
+    #[test]
+    fn test_harsh_min_pen_floor_flags_bankruptcy() {
+        let mut config = config();
+        config.min_pen = 1_000_000.0; // unreachable annual floor
+        let pwd = PwdAssumptions::default();
+        let mut returns = FixedReturnGenerator { assumed_return: 0.04 };
+        let mut rng = scenario_rng(&StochasticConfig::default(), 0);
+
+        let outcome = run_retirement_scenario(&config, &pwd, &mut returns, &mut rng, log_utility);
+        assert!(outcome.bankrupt);
+    }
+}
@@ -0,0 +1,229 @@
+//! Economic scenario generator for stochastic indexed crediting
+//!
+//! `CreditingApproach::PolicyBased` credits indexed policies at a single deterministic
+//! `DEFAULT_INDEXED_ANNUAL_RATE`, so a block run can't see how the indexed buckets (or
+//! `hedge_gains`) behave across a distribution of market paths. A full stochastic crediting mode
+//! would add a `CreditingApproach::Stochastic` variant that re-runs the engine's monthly
+//! decrement loop against a path-dependent credited rate each year — that variant lives on
+//! `CreditingApproach` itself, which isn't available to extend in this tree (see [`super::reserves`]
+//! for the same kind of limitation). This module instead draws each scenario's annual index
+//! returns from [`RegimeSwitchingLognormalGenerator`], applies the FIA point-to-point crediting
+//! formula to each year, and collapses the resulting path into a single average credited rate
+//! used as that scenario's constant `indexed_annual_rate`. Swap [`scenario_indexed_rate`]'s
+//! averaging step for a true path-dependent `CreditingApproach::Stochastic` run once that engine
+//! variant exists.
+
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use super::{
+    scenario_rng, CreditingApproach, IndexReturnGenerator, ProjectionConfig, ProjectionEngine,
+    RegimeSwitchingLognormalGenerator, ScenarioPv, StochasticConfig, StochasticResult,
+    point_to_point_credit,
+};
+use crate::policy::Policy;
+use crate::Assumptions;
+
+/// Two-regime (bull/bear) lognormal market model plus the FIA crediting terms used to turn a
+/// drawn index return into a credited rate.
+#[derive(Debug, Clone)]
+pub struct EconomicScenarioConfig {
+    /// Per-regime lognormal drift, `[bull, bear]`.
+    pub regime_mu: [f64; 2],
+    /// Per-regime lognormal volatility, `[bull, bear]`.
+    pub regime_sigma: [f64; 2],
+    /// Row-stochastic regime transition matrix; `transition[r]` gives the probability of moving
+    /// to regime 0 and regime 1 from regime `r`.
+    pub transition: [[f64; 2]; 2],
+    pub starting_regime: usize,
+    pub participation: f64,
+    pub spread: f64,
+    pub floor: f64,
+    pub cap: f64,
+    /// Number of independent scenarios to draw.
+    pub scenarios: u32,
+    /// RNG seed; reused for reproducibility across runs of the same scenario count.
+    pub seed: u64,
+    /// Years of annual index draws averaged into each scenario's constant credited rate.
+    pub draw_years: u32,
+}
+
+impl Default for EconomicScenarioConfig {
+    fn default() -> Self {
+        Self {
+            regime_mu: [0.06, -0.02],
+            regime_sigma: [0.08, 0.20],
+            transition: [[0.95, 0.05], [0.30, 0.70]],
+            starting_regime: 0,
+            participation: 0.80,
+            spread: 0.0,
+            floor: 0.0,
+            cap: 0.10,
+            scenarios: 100,
+            seed: 42,
+            draw_years: 30,
+        }
+    }
+}
+
+/// Draw `config.draw_years` annual index returns for scenario `scenario_index`, apply
+/// point-to-point crediting (cap/floor/participation) to each year, and average the result into
+/// a single constant rate for `CreditingApproach::PolicyBased`'s `indexed_annual_rate`.
+pub fn scenario_indexed_rate(config: &EconomicScenarioConfig, scenario_index: u32) -> f64 {
+    let seed_config = StochasticConfig { scenarios: config.scenarios, seed: config.seed };
+    let mut rng: ChaCha8Rng = scenario_rng(&seed_config, scenario_index);
+    let mut generator = RegimeSwitchingLognormalGenerator::new(
+        config.regime_mu,
+        config.regime_sigma,
+        config.transition,
+        config.starting_regime,
+    );
+
+    let draw_years = config.draw_years.max(1);
+    let credited_sum: f64 = (0..draw_years)
+        .map(|_| {
+            let index_return = generator.next_annual_return(&mut rng);
+            point_to_point_credit(index_return, config.participation, config.spread, config.floor, config.cap)
+        })
+        .sum();
+    credited_sum / draw_years as f64
+}
+
+/// One scenario's block-level outcome: the constant indexed rate it drew, and the monthly sum
+/// of `total_net_cashflow` across all projected policies.
+#[derive(Debug, Clone)]
+pub struct ScenarioBlockRow {
+    pub scenario_index: u32,
+    pub indexed_rate: f64,
+    pub monthly_net_cashflow: Vec<f64>,
+}
+
+/// Run `scenario_config.scenarios` independent block projections — each under its own drawn
+/// indexed credited rate — across `policies`, in parallel over scenarios.
+pub fn run_scenario_block(
+    policies: &[Policy],
+    assumptions: &Assumptions,
+    base_config: &ProjectionConfig,
+    scenario_config: &EconomicScenarioConfig,
+) -> Vec<ScenarioBlockRow> {
+    (0..scenario_config.scenarios)
+        .into_par_iter()
+        .map(|scenario_index| {
+            let indexed_rate = scenario_indexed_rate(scenario_config, scenario_index);
+            let mut config = base_config.clone();
+            if let CreditingApproach::PolicyBased { fixed_annual_rate, .. } = config.crediting {
+                config.crediting = CreditingApproach::PolicyBased { fixed_annual_rate, indexed_annual_rate: indexed_rate };
+            }
+
+            let mut monthly_net_cashflow = vec![0.0; base_config.projection_months as usize];
+            for policy in policies {
+                let engine = ProjectionEngine::new(assumptions.clone(), config.clone());
+                for row in engine.project_policy(policy).cashflows {
+                    let idx = (row.projection_month - 1) as usize;
+                    if idx < monthly_net_cashflow.len() {
+                        monthly_net_cashflow[idx] += row.total_net_cashflow;
+                    }
+                }
+            }
+
+            ScenarioBlockRow { scenario_index, indexed_rate, monthly_net_cashflow }
+        })
+        .collect()
+}
+
+/// Discount one scenario's monthly net cashflow path to a single present value at the
+/// monthly-compounded equivalent of `annual_discount_rate`.
+fn scenario_pv(monthly_net_cashflow: &[f64], annual_discount_rate: f64) -> f64 {
+    let monthly_rate = (1.0 + annual_discount_rate).powf(1.0 / 12.0) - 1.0;
+    monthly_net_cashflow
+        .iter()
+        .enumerate()
+        .map(|(i, cashflow)| cashflow / (1.0 + monthly_rate).powi(i as i32 + 1))
+        .sum()
+}
+
+/// Distribution summary of scenario present values: the mean plus CTE70/CTE90 tail risk.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScenarioSummary {
+    pub mean: f64,
+    pub cte70: f64,
+    pub cte90: f64,
+}
+
+/// Discount each scenario's monthly net cashflow path and summarize the resulting PV
+/// distribution, reusing [`StochasticResult`]'s existing percentile/CTE machinery.
+pub fn summarize_scenarios(rows: &[ScenarioBlockRow], annual_discount_rate: f64) -> ScenarioSummary {
+    let result = StochasticResult {
+        scenario_pvs: rows
+            .iter()
+            .map(|row| ScenarioPv {
+                pv_benefits: scenario_pv(&row.monthly_net_cashflow, annual_discount_rate),
+                ..Default::default()
+            })
+            .collect(),
+    };
+
+    let mean = if result.scenario_pvs.is_empty() {
+        0.0
+    } else {
+        result.scenario_pvs.iter().map(|s| s.pv_benefits).sum::<f64>() / result.scenario_pvs.len() as f64
+    };
+
+    ScenarioSummary { mean, cte70: result.cte_benefits(0.70), cte90: result.cte_benefits(0.90) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::load_default_inforce;
+    use crate::projection::{CreditingApproach, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE};
+
+    fn config() -> ProjectionConfig {
+        ProjectionConfig {
+            projection_months: 24,
+            crediting: CreditingApproach::PolicyBased {
+                fixed_annual_rate: DEFAULT_FIXED_ANNUAL_RATE,
+                indexed_annual_rate: DEFAULT_INDEXED_ANNUAL_RATE,
+            },
+            detailed_output: false,
+            treasury_change: 0.0,
+            fixed_lapse_rate: None,
+            hedge_params: None,
+        }
+    }
+
+    #[test]
+    fn test_scenario_indexed_rate_is_reproducible_and_bounded() {
+        let scenario_config = EconomicScenarioConfig { scenarios: 10, draw_years: 20, ..EconomicScenarioConfig::default() };
+        let a = scenario_indexed_rate(&scenario_config, 3);
+        let b = scenario_indexed_rate(&scenario_config, 3);
+        assert_eq!(a, b);
+        assert!(a >= scenario_config.floor - 1e-9 && a <= scenario_config.cap + 1e-9);
+    }
+
+    #[test]
+    fn test_different_scenario_indices_diverge() {
+        let scenario_config = EconomicScenarioConfig { scenarios: 10, draw_years: 20, ..EconomicScenarioConfig::default() };
+        let rates: Vec<f64> = (0..10).map(|i| scenario_indexed_rate(&scenario_config, i)).collect();
+        assert!(rates.iter().any(|&r| (r - rates[0]).abs() > 1e-9));
+    }
+
+    #[test]
+    fn test_run_scenario_block_and_summarize() {
+        let policies = load_default_inforce().expect("pricing inforce loads");
+        let assumptions = Assumptions::default_pricing();
+        let cfg = config();
+        let scenario_config = EconomicScenarioConfig { scenarios: 5, draw_years: 5, ..EconomicScenarioConfig::default() };
+
+        let rows = run_scenario_block(&policies, &assumptions, &cfg, &scenario_config);
+        assert_eq!(rows.len(), 5);
+        for row in &rows {
+            assert_eq!(row.monthly_net_cashflow.len(), cfg.projection_months as usize);
+        }
+
+        let summary = summarize_scenarios(&rows, 0.03);
+        assert!(summary.mean.is_finite());
+        // CTE90 averages a smaller (or equal), more extreme tail than CTE70.
+        assert!(summary.cte90.is_finite() && summary.cte70.is_finite());
+    }
+}
@@ -0,0 +1,339 @@
+//! Parameter-sweep sensitivity harness over `AdjustmentParams` and projection-side shocks
+//!
+//! `AdjustmentParams`/`load_adjusted_inforce` apply one adjustment set per call, so studying how
+//! block economics move with assumptions otherwise means manually re-running the block for each
+//! parameter combination. `SensitivityGrid` takes a base `AdjustmentParams`/`ProjectionConfig`
+//! plus a list of axes, forms either the full Cartesian product or a one-at-a-time sweep (holding
+//! every other axis at its base value), re-runs the block for each cell, and reports the key
+//! outputs an actuary would tabulate by hand: PV of net cashflow, the terminal-month BOP account
+//! value, and total hedge gains. `tornado_ranking` turns the one-at-a-time sweep into a
+//! largest-marginal-impact-first ranking.
+
+use rayon::prelude::*;
+
+use super::{ProjectionConfig, ProjectionEngine};
+use crate::policy::{load_adjusted_inforce, AdjustmentParams};
+use crate::Assumptions;
+
+/// One axis of the sweep: a named `AdjustmentParams` field (or the projection-side
+/// `treasury_change` shock) and the values to try for it.
+#[derive(Debug, Clone)]
+pub enum SensitivityAxis {
+    FixedPct(Vec<f64>),
+    BbBonus(Vec<f64>),
+    MaleMult(Vec<f64>),
+    FemaleMult(Vec<f64>),
+    QualMult(Vec<f64>),
+    NonqualMult(Vec<f64>),
+    TargetPremium(Vec<f64>),
+    /// Projection-side shock, not an `AdjustmentParams` field.
+    TreasuryChange(Vec<f64>),
+}
+
+impl SensitivityAxis {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SensitivityAxis::FixedPct(_) => "fixed_pct",
+            SensitivityAxis::BbBonus(_) => "bb_bonus",
+            SensitivityAxis::MaleMult(_) => "male_mult",
+            SensitivityAxis::FemaleMult(_) => "female_mult",
+            SensitivityAxis::QualMult(_) => "qual_mult",
+            SensitivityAxis::NonqualMult(_) => "nonqual_mult",
+            SensitivityAxis::TargetPremium(_) => "target_premium",
+            SensitivityAxis::TreasuryChange(_) => "treasury_change",
+        }
+    }
+
+    pub fn values(&self) -> &[f64] {
+        match self {
+            SensitivityAxis::FixedPct(v)
+            | SensitivityAxis::BbBonus(v)
+            | SensitivityAxis::MaleMult(v)
+            | SensitivityAxis::FemaleMult(v)
+            | SensitivityAxis::QualMult(v)
+            | SensitivityAxis::NonqualMult(v)
+            | SensitivityAxis::TargetPremium(v)
+            | SensitivityAxis::TreasuryChange(v) => v,
+        }
+    }
+
+    fn apply(&self, value: f64, params: &mut AdjustmentParams, config: &mut ProjectionConfig) {
+        match self {
+            SensitivityAxis::FixedPct(_) => params.fixed_pct = value,
+            SensitivityAxis::BbBonus(_) => params.bb_bonus = value,
+            SensitivityAxis::MaleMult(_) => params.male_mult = value,
+            SensitivityAxis::FemaleMult(_) => params.female_mult = value,
+            SensitivityAxis::QualMult(_) => params.qual_mult = value,
+            SensitivityAxis::NonqualMult(_) => params.nonqual_mult = value,
+            SensitivityAxis::TargetPremium(_) => params.target_premium = value,
+            SensitivityAxis::TreasuryChange(_) => config.treasury_change = value,
+        }
+    }
+
+    fn base_value(&self, params: &AdjustmentParams, config: &ProjectionConfig) -> f64 {
+        match self {
+            SensitivityAxis::FixedPct(_) => params.fixed_pct,
+            SensitivityAxis::BbBonus(_) => params.bb_bonus,
+            SensitivityAxis::MaleMult(_) => params.male_mult,
+            SensitivityAxis::FemaleMult(_) => params.female_mult,
+            SensitivityAxis::QualMult(_) => params.qual_mult,
+            SensitivityAxis::NonqualMult(_) => params.nonqual_mult,
+            SensitivityAxis::TargetPremium(_) => params.target_premium,
+            SensitivityAxis::TreasuryChange(_) => config.treasury_change,
+        }
+    }
+}
+
+/// One cell of the sweep: the axis values that produced it, in long (tidy) form, plus the key
+/// block outputs for that combination.
+#[derive(Debug, Clone)]
+pub struct SensitivityCell {
+    pub axis_values: Vec<(String, f64)>,
+    pub pv_net_cashflow: f64,
+    pub terminal_bop_av: f64,
+    pub total_hedge_gains: f64,
+}
+
+/// Parameter-sweep driver: a base `AdjustmentParams`/`ProjectionConfig` and the axes to vary.
+#[derive(Debug, Clone)]
+pub struct SensitivityGrid {
+    pub base_params: AdjustmentParams,
+    pub base_config: ProjectionConfig,
+    pub axes: Vec<SensitivityAxis>,
+    /// Annual discount rate used to PV each cell's monthly net cashflow.
+    pub discount_rate: f64,
+}
+
+impl SensitivityGrid {
+    pub fn new(
+        base_params: AdjustmentParams,
+        base_config: ProjectionConfig,
+        axes: Vec<SensitivityAxis>,
+        discount_rate: f64,
+    ) -> Self {
+        Self { base_params, base_config, axes, discount_rate }
+    }
+
+    /// Full Cartesian product of every axis's values, run in parallel across cells.
+    pub fn run_full_grid(&self, assumptions: &Assumptions) -> Vec<SensitivityCell> {
+        self.cartesian_combinations()
+            .into_par_iter()
+            .map(|combo| self.run_cell(assumptions, &combo))
+            .collect()
+    }
+
+    /// One-at-a-time sweep: for each axis, hold every other axis at its base value and vary
+    /// only that axis across its values.
+    pub fn run_one_at_a_time(&self, assumptions: &Assumptions) -> Vec<SensitivityCell> {
+        let combos: Vec<Vec<Option<f64>>> = self
+            .axes
+            .iter()
+            .enumerate()
+            .flat_map(|(axis_idx, axis)| {
+                axis.values().iter().map(move |&value| {
+                    let mut combo = vec![None; self.axes.len()];
+                    combo[axis_idx] = Some(value);
+                    combo
+                })
+            })
+            .collect();
+
+        combos.into_par_iter().map(|combo| self.run_cell(assumptions, &combo)).collect()
+    }
+
+    /// Tornado-style ranking: for each axis, the spread (max − min) of `pv_net_cashflow` across
+    /// that axis's one-at-a-time sweep, sorted with the largest marginal impact first.
+    pub fn tornado_ranking(&self, assumptions: &Assumptions) -> Vec<(String, f64)> {
+        let cells = self.run_one_at_a_time(assumptions);
+
+        let mut ranking: Vec<(String, f64)> = self
+            .axes
+            .iter()
+            .map(|axis| {
+                let pvs: Vec<f64> = cells
+                    .iter()
+                    .filter(|cell| cell.axis_values.iter().any(|(name, _)| name == axis.name()))
+                    .map(|cell| cell.pv_net_cashflow)
+                    .collect();
+                let spread = pvs.iter().cloned().fold(f64::MIN, f64::max)
+                    - pvs.iter().cloned().fold(f64::MAX, f64::min);
+                (axis.name().to_string(), spread)
+            })
+            .collect();
+
+        ranking.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        ranking
+    }
+
+    fn cartesian_combinations(&self) -> Vec<Vec<Option<f64>>> {
+        let mut combos: Vec<Vec<Option<f64>>> = vec![vec![]];
+        for axis in &self.axes {
+            let mut next = Vec::new();
+            for combo in &combos {
+                for &value in axis.values() {
+                    let mut extended = combo.clone();
+                    extended.push(Some(value));
+                    next.push(extended);
+                }
+            }
+            combos = next;
+        }
+        combos
+    }
+
+    fn run_cell(&self, assumptions: &Assumptions, combo: &[Option<f64>]) -> SensitivityCell {
+        let mut params = self.base_params.clone();
+        let mut config = self.base_config.clone();
+        let mut axis_values = Vec::with_capacity(self.axes.len());
+
+        for (axis, value) in self.axes.iter().zip(combo.iter()) {
+            let applied = value.unwrap_or_else(|| axis.base_value(&self.base_params, &self.base_config));
+            axis.apply(applied, &mut params, &mut config);
+            // Only record axes this cell actually swept (`combo[idx]` is `Some`) — axes held at
+            // base (`None`, one-at-a-time mode) are applied to `params`/`config` above but don't
+            // get an entry, so `tornado_ranking`'s per-axis filter sees only that axis's own
+            // sweep cells instead of every cell in the run.
+            if let Some(value) = value {
+                axis_values.push((axis.name().to_string(), *value));
+            }
+        }
+
+        let policies = load_adjusted_inforce(&params).expect("adjusted inforce loads");
+        let monthly_rate = (1.0 + self.discount_rate).powf(1.0 / 12.0) - 1.0;
+
+        let mut pv_net_cashflow = 0.0;
+        let mut terminal_bop_av = 0.0;
+        let mut total_hedge_gains = 0.0;
+
+        for policy in &policies {
+            let engine = ProjectionEngine::new(assumptions.clone(), config.clone());
+            for row in engine.project_policy(policy).cashflows {
+                let discount = 1.0 / (1.0 + monthly_rate).powi(row.projection_month as i32);
+                pv_net_cashflow += row.total_net_cashflow * discount;
+                total_hedge_gains += row.hedge_gains;
+                if row.projection_month == config.projection_months {
+                    terminal_bop_av += row.bop_av;
+                }
+            }
+        }
+
+        SensitivityCell { axis_values, pv_net_cashflow, terminal_bop_av, total_hedge_gains }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projection::{CreditingApproach, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE};
+
+    fn config() -> ProjectionConfig {
+        ProjectionConfig {
+            projection_months: 24,
+            crediting: CreditingApproach::PolicyBased {
+                fixed_annual_rate: DEFAULT_FIXED_ANNUAL_RATE,
+                indexed_annual_rate: DEFAULT_INDEXED_ANNUAL_RATE,
+            },
+            detailed_output: false,
+            treasury_change: 0.0,
+            fixed_lapse_rate: None,
+            hedge_params: None,
+        }
+    }
+
+    #[test]
+    fn test_full_grid_has_cartesian_product_cell_count() {
+        let grid = SensitivityGrid::new(
+            AdjustmentParams::default(),
+            config(),
+            vec![
+                SensitivityAxis::FixedPct(vec![0.25, 0.50, 0.75]),
+                SensitivityAxis::BbBonus(vec![0.30, 0.40]),
+            ],
+            0.03,
+        );
+        let cells = grid.run_full_grid(&Assumptions::default_pricing());
+        assert_eq!(cells.len(), 6);
+        for cell in &cells {
+            assert_eq!(cell.axis_values.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_one_at_a_time_holds_other_axes_at_base() {
+        let grid = SensitivityGrid::new(
+            AdjustmentParams::default(),
+            config(),
+            vec![
+                SensitivityAxis::FixedPct(vec![0.25, 0.50, 0.75]),
+                SensitivityAxis::BbBonus(vec![0.30, 0.40]),
+            ],
+            0.03,
+        );
+        let cells = grid.run_one_at_a_time(&Assumptions::default_pricing());
+        // 3 fixed_pct values + 2 bb_bonus values = 5 cells, one axis varied per cell.
+        assert_eq!(cells.len(), 5);
+        // Each cell records only the axis it actually swept — the other axis was held at base
+        // when running the projection, but isn't listed, so per-axis filtering downstream (e.g.
+        // `tornado_ranking`) doesn't mistake a base-held axis for a swept one.
+        for cell in &cells {
+            assert_eq!(cell.axis_values.len(), 1);
+        }
+        let fixed_pct_cells: Vec<_> =
+            cells.iter().filter(|c| c.axis_values[0].0 == "fixed_pct").collect();
+        let bb_bonus_cells: Vec<_> =
+            cells.iter().filter(|c| c.axis_values[0].0 == "bb_bonus").collect();
+        assert_eq!(fixed_pct_cells.len(), 3);
+        assert_eq!(bb_bonus_cells.len(), 2);
+    }
+
+    #[test]
+    fn test_tornado_ranking_orders_by_descending_spread() {
+        let grid = SensitivityGrid::new(
+            AdjustmentParams::default(),
+            config(),
+            vec![
+                SensitivityAxis::FixedPct(vec![0.0, 1.0]),
+                SensitivityAxis::TargetPremium(vec![100_000_000.0, 100_000_001.0]),
+            ],
+            0.03,
+        );
+        let ranking = grid.tornado_ranking(&Assumptions::default_pricing());
+        assert_eq!(ranking.len(), 2);
+        // Strictly greater, not just >=: with axis_values mixed across axes (the bug this guards
+        // against), both axes' spreads are computed from the same pooled cell set and come out
+        // identical, which a `>=` check can't distinguish from a correct, isolated computation.
+        assert!(ranking[0].1 > ranking[1].1);
+        // Swinging fixed_pct from 0% to 100% Fixed should dominate a $1 premium-target nudge.
+        assert_eq!(ranking[0].0, "fixed_pct");
+    }
+
+    #[test]
+    fn test_tornado_ranking_isolates_each_axis_spread() {
+        // Three axes, each with a visibly different spread, so a per-axis filter that leaks
+        // other axes' cells into the pool would distort more than just first-place ordering.
+        let grid = SensitivityGrid::new(
+            AdjustmentParams::default(),
+            config(),
+            vec![
+                SensitivityAxis::FixedPct(vec![0.0, 1.0]),
+                SensitivityAxis::BbBonus(vec![0.0, 1.0]),
+                SensitivityAxis::TargetPremium(vec![100_000_000.0, 100_000_001.0]),
+            ],
+            0.03,
+        );
+        let ranking = grid.tornado_ranking(&Assumptions::default_pricing());
+        assert_eq!(ranking.len(), 3);
+
+        // If axis pools leaked into each other, every axis would report the same (max-over-all)
+        // spread; confirm they're genuinely distinct instead.
+        let spreads: Vec<f64> = ranking.iter().map(|(_, spread)| *spread).collect();
+        assert_ne!(spreads[0], spreads[1]);
+        assert_ne!(spreads[1], spreads[2]);
+
+        // Sorted descending.
+        assert!(ranking[0].1 >= ranking[1].1);
+        assert!(ranking[1].1 >= ranking[2].1);
+        // A $1 premium-target nudge should be the least impactful of the three.
+        assert_eq!(ranking.last().unwrap().0, "target_premium");
+    }
+}
@@ -0,0 +1,255 @@
+//! Stochastic Monte Carlo projection mode
+//!
+//! The engine elsewhere in this module runs a single deterministic expected-value path per
+//! policy. This module adds the scaffolding for a microsimulation mode that instead runs `N`
+//! scenarios and reports distributions: each scenario draws mortality against the period `qx`,
+//! draws lapse against the dynamic lapse rate, and draws GLWB activation timing from the
+//! `UtilizationRate` curve treated as a discrete distribution over `glwb_start_year`, rather
+//! than applying all three as fixed expected-value weights.
+//!
+//! `run_stochastic` owns the Monte Carlo scaffolding (reproducible per-scenario RNG, draws,
+//! percentile/CTE aggregation); the monthly decrement loop itself is supplied by the caller as
+//! `run_scenario`. A true implementation would have `ProjectionEngine` re-run `project_policy`'s
+//! month loop against drawn outcomes instead of expected-value fractions, but `ProjectionEngine`'s
+//! internals aren't available to extend in this tree, so [`run_policy_scenario`] approximates it
+//! at the call site instead: it walks a policy's own real `project_policy` output and draws
+//! against the per-month hazard rates already computed there. `src/bin/run_block.rs` wires this
+//! into `run_stochastic` against a real `ProjectionResult`. The existing deterministic path is
+//! the degenerate `scenarios: 1` case of this mode.
+
+use rand::Rng;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use super::CashflowRow;
+
+/// One scenario's drawn mortality/lapse/GLWB-activation outcome for a single projected month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonthlyDraw {
+    pub mortality: bool,
+    pub lapse: bool,
+    pub glwb_activates: bool,
+}
+
+/// Draw the month's decrements against an RNG, given the period rates that would otherwise be
+/// applied as expected-value fractions.
+///
+/// `qx` is the period mortality rate, `lapse_rate` the (possibly dynamic) lapse rate for the
+/// month, and `activation_prob` the probability GLWB income activates this month, read off the
+/// `UtilizationRate` curve as a discrete hazard rather than a fixed weight.
+pub fn draw_month<R: Rng + ?Sized>(rng: &mut R, qx: f64, lapse_rate: f64, activation_prob: f64) -> MonthlyDraw {
+    MonthlyDraw {
+        mortality: rng.gen::<f64>() < qx,
+        lapse: rng.gen::<f64>() < lapse_rate,
+        glwb_activates: rng.gen::<f64>() < activation_prob,
+    }
+}
+
+/// Present values accumulated over one scenario's projected lifetime.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScenarioPv {
+    pub pv_benefits: f64,
+    pub pv_reserves: f64,
+    pub pv_profit: f64,
+}
+
+/// Controls a stochastic run: number of scenarios and the RNG seed.
+///
+/// `scenarios: 1` reduces the mode to the existing deterministic expected-value path, since a
+/// single scenario's percentile/CTE summaries all collapse to that one path's value.
+#[derive(Debug, Clone)]
+pub struct StochasticConfig {
+    pub scenarios: u32,
+    pub seed: u64,
+}
+
+impl Default for StochasticConfig {
+    fn default() -> Self {
+        Self { scenarios: 1, seed: 42 }
+    }
+}
+
+/// Distribution of per-scenario present values produced by a stochastic run.
+#[derive(Debug, Clone, Default)]
+pub struct StochasticResult {
+    pub scenario_pvs: Vec<ScenarioPv>,
+}
+
+impl StochasticResult {
+    /// Percentile (e.g. 0.50, 0.95, 0.99) of `pv_benefits` across scenarios.
+    pub fn percentile_benefits(&self, percentile: f64) -> f64 {
+        percentile_of(&self.pv_benefits(), percentile)
+    }
+
+    /// CTE (Conditional Tail Expectation) of `pv_benefits` at the given percentile, e.g.
+    /// `cte_benefits(0.70)` averages the worst 30% of scenarios by PV of benefits.
+    pub fn cte_benefits(&self, percentile: f64) -> f64 {
+        cte(&self.pv_benefits(), percentile)
+    }
+
+    fn pv_benefits(&self) -> Vec<f64> {
+        self.scenario_pvs.iter().map(|s| s.pv_benefits).collect()
+    }
+}
+
+fn percentile_of(values: &[f64], percentile: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let idx = (((sorted.len() - 1) as f64) * percentile).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+/// Average of the worst `(1 - percentile)` fraction of values (e.g. CTE70 averages the worst
+/// 30%), the standard tail-risk summary for a stochastic benefit/reserve distribution.
+fn cte(values: &[f64], percentile: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| b.partial_cmp(a).unwrap()); // worst (highest) first
+    // Subtract a small epsilon before `ceil` so e.g. `percentile=0.70` against 10 values computes
+    // a tail count of 3, not 4 — `1.0 - 0.70` isn't exactly `0.30` in f64, so the un-nudged
+    // product can land a hair above the intended integer and round up to the next one.
+    let tail_count = ((((1.0 - percentile) * sorted.len() as f64) - 1e-9).ceil() as usize).max(1);
+    let tail = &sorted[..tail_count.min(sorted.len())];
+    tail.iter().sum::<f64>() / tail.len() as f64
+}
+
+/// Build a reproducible per-scenario RNG from a `StochasticConfig` seed and scenario index, so
+/// re-running the same config and scenario number always draws the same path.
+pub fn scenario_rng(config: &StochasticConfig, scenario_index: u32) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(config.seed.wrapping_add(scenario_index as u64))
+}
+
+/// Run one Monte Carlo scenario for a single policy against its own deterministic projection.
+///
+/// `cashflows` is a real `ProjectionResult::cashflows` — `ProjectionEngine::project_policy`'s
+/// output under whatever `ProjectionConfig` produced it — so `final_mortality`/`final_lapse_rate`/
+/// `non_systematic_pwd_rate` are the same per-month hazard rates the deterministic path applies
+/// as expected-value fractions. This walks those months drawing against them instead, stopping at
+/// whichever decrement (mortality or lapse) fires first for this simulated life. Through that
+/// point it discounts at `discount_rate` the PWD benefit outflow into `pv_benefits` and rider
+/// charges collected net of that outflow into `pv_profit`.
+///
+/// This is the module doc's "re-run the month loop against drawn outcomes" design, approximated
+/// at the call site rather than inside `ProjectionEngine` itself: the per-month hazard rates come
+/// from a real `project_policy` run, but the branching on drawn outcomes happens here, since
+/// `ProjectionEngine`'s internals aren't available to extend in this tree.
+pub fn run_policy_scenario<R: Rng + ?Sized>(
+    rng: &mut R,
+    cashflows: &[CashflowRow],
+    discount_rate: f64,
+) -> ScenarioPv {
+    let monthly_discount_rate = (1.0 + discount_rate).powf(1.0 / 12.0) - 1.0;
+    let mut pv_benefits = 0.0;
+    let mut pv_profit = 0.0;
+
+    for row in cashflows {
+        let draw = draw_month(rng, row.final_mortality, row.final_lapse_rate, row.non_systematic_pwd_rate);
+        let discount = 1.0 / (1.0 + monthly_discount_rate).powi((row.projection_month - 1) as i32);
+
+        pv_benefits += row.pwd_dec * discount;
+        pv_profit += (row.rider_charges_dec - row.pwd_dec) * discount;
+
+        if draw.mortality || draw.lapse {
+            break;
+        }
+    }
+
+    ScenarioPv { pv_benefits, pv_reserves: 0.0, pv_profit }
+}
+
+/// Run `config.scenarios` independent paths, invoking `run_scenario` once per scenario with a
+/// reproducible RNG, and collect the resulting present values.
+pub fn run_stochastic<F>(config: &StochasticConfig, mut run_scenario: F) -> StochasticResult
+where
+    F: FnMut(&mut ChaCha8Rng, u32) -> ScenarioPv,
+{
+    let mut scenario_pvs = Vec::with_capacity(config.scenarios as usize);
+    for i in 0..config.scenarios {
+        let mut rng = scenario_rng(config, i);
+        scenario_pvs.push(run_scenario(&mut rng, i));
+    }
+    StochasticResult { scenario_pvs }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_degenerate_case() {
+        let config = StochasticConfig { scenarios: 1, seed: 7 };
+        let result = run_stochastic(&config, |_rng, _i| ScenarioPv { pv_benefits: 123.0, pv_reserves: 10.0, pv_profit: 5.0 });
+        assert_eq!(result.percentile_benefits(0.50), 123.0);
+        assert_eq!(result.cte_benefits(0.99), 123.0);
+    }
+
+    #[test]
+    fn test_same_seed_reproducible() {
+        let config = StochasticConfig { scenarios: 5, seed: 99 };
+        let run = |config: &StochasticConfig| {
+            run_stochastic(config, |rng, _i| ScenarioPv {
+                pv_benefits: rng.gen::<f64>(),
+                ..Default::default()
+            })
+        };
+        let a = run(&config);
+        let b = run(&config);
+        for (x, y) in a.scenario_pvs.iter().zip(b.scenario_pvs.iter()) {
+            assert_eq!(x.pv_benefits, y.pv_benefits);
+        }
+    }
+
+    #[test]
+    fn test_cte_averages_worst_tail() {
+        let values = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        // CTE70 averages the worst 30% (3 of 10 values): 8, 9, 10
+        assert!((cte(&values, 0.70) - 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_percentile_of_sorted_values() {
+        let values = vec![10.0, 1.0, 5.0, 3.0, 2.0];
+        assert_eq!(percentile_of(&values, 0.0), 1.0);
+        assert_eq!(percentile_of(&values, 1.0), 10.0);
+    }
+
+    #[test]
+    fn test_run_policy_scenario_over_real_projection_produces_finite_reproducible_pvs() {
+        use crate::policy::load_default_inforce;
+        use crate::projection::{CreditingApproach, ProjectionConfig, ProjectionEngine, DEFAULT_FIXED_ANNUAL_RATE, DEFAULT_INDEXED_ANNUAL_RATE};
+        use crate::Assumptions;
+
+        let policies = load_default_inforce().expect("pricing inforce loads");
+        let policy = &policies[0];
+        let assumptions = Assumptions::default_pricing();
+        let cfg = ProjectionConfig {
+            projection_months: 120,
+            crediting: CreditingApproach::PolicyBased {
+                fixed_annual_rate: DEFAULT_FIXED_ANNUAL_RATE,
+                indexed_annual_rate: DEFAULT_INDEXED_ANNUAL_RATE,
+            },
+            detailed_output: false,
+            treasury_change: 0.0,
+            fixed_lapse_rate: None,
+            hedge_params: None,
+        };
+        let engine = ProjectionEngine::new(assumptions, cfg);
+        let cashflows = engine.project_policy(policy).cashflows;
+
+        let config = StochasticConfig { scenarios: 200, seed: 11 };
+        let result = run_stochastic(&config, |rng, _i| run_policy_scenario(rng, &cashflows, 0.03));
+
+        assert_eq!(result.scenario_pvs.len(), 200);
+        assert!(result.scenario_pvs.iter().all(|pv| pv.pv_benefits.is_finite()));
+
+        let rerun = run_stochastic(&config, |rng, _i| run_policy_scenario(rng, &cashflows, 0.03));
+        for (a, b) in result.scenario_pvs.iter().zip(rerun.scenario_pvs.iter()) {
+            assert_eq!(a.pv_benefits, b.pv_benefits);
+        }
+    }
+}